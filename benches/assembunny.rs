@@ -0,0 +1,69 @@
+extern crate advent2016;
+
+use advent2016::assembunny::bytecode::Program;
+use advent2016::assembunny::{Machine, Registers};
+use std::time::{Duration, Instant};
+
+const DAY12: &str = "cpy 1 a
+cpy 1 b
+cpy 26 d
+jnz c 2
+jnz 1 5
+cpy 7 c
+inc d
+dec c
+jnz c -2
+cpy a c
+inc a
+dec b
+jnz b -2
+cpy c b
+dec d
+jnz d -6
+cpy 13 c
+cpy 14 d
+inc a
+dec d
+jnz d -2
+dec c
+jnz c -5";
+
+const RUNS: usize = 5;
+
+fn fastest<F: FnMut() -> Registers>(mut run: F) -> (Duration, Registers) {
+    let mut best = None;
+    let mut registers = Registers::default();
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        registers = run();
+        let elapsed = start.elapsed();
+        if best.is_none_or(|best| elapsed < best) {
+            best = Some(elapsed);
+        }
+    }
+    (best.unwrap(), registers)
+}
+
+fn report(name: &str, (elapsed, registers): (Duration, Registers)) {
+    println!("{:<24} {:>10.3} ms   {}", name, elapsed.as_secs_f64() * 1000.0, registers);
+}
+
+fn main() {
+    let start = Registers { c: 1, ..Registers::default() };
+    for optimized in [false, true].iter() {
+        let label = if *optimized { "optimized" } else { "unoptimized" };
+        let mut source = Machine::new();
+        source.set_optimized(*optimized);
+        source.load(DAY12).unwrap();
+
+        report(&format!("machine, {}", label), fastest(|| {
+            let mut machine = Machine::with_registers(start);
+            machine.set_optimized(*optimized);
+            machine.load_instructions(source.instructions().to_vec());
+            machine.run()
+        }));
+
+        let program: Program = source.compile();
+        report(&format!("bytecode, {}", label), fastest(|| program.run(start)));
+    }
+}
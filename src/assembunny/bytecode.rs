@@ -0,0 +1,352 @@
+use assembunny::optimizer;
+use assembunny::{Instruction, Operand, Register, Registers};
+
+// Each op carries a pointer to the function that executes it, so running a
+// program is a loop of indirect calls with no decoding or matching.
+type Handler = fn(&mut Vm, &Op, usize) -> usize;
+
+#[derive(Clone, Copy)]
+pub struct Op {
+    handler: Handler,
+    x: isize,
+    y: isize,
+    z: isize,
+    w: isize,
+}
+
+pub struct Program {
+    source: Vec<Instruction>,
+    optimized: bool,
+    ops: Vec<Op>,
+    plain: Vec<Op>,
+}
+
+impl Program {
+    pub fn compile(instructions: &[Instruction], optimized: bool) -> Program {
+        let (ops, plain) = compile_all(instructions, optimized);
+        Program {
+            source: instructions.to_vec(),
+            optimized,
+            ops,
+            plain,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    pub fn run(&self, registers: Registers) -> Registers {
+        self.run_with_output(registers, |_| true)
+    }
+
+    pub fn run_with_output<F: FnMut(isize) -> bool>(&self, registers: Registers, mut sink: F) -> Registers {
+        let mut vm = Vm {
+            registers: [registers.a, registers.b, registers.c, registers.d],
+            source: self.source.clone(),
+            optimized: self.optimized,
+            ops: self.ops.clone(),
+            plain: self.plain.clone(),
+            output: None,
+        };
+        let mut ip = 0;
+        while ip < vm.ops.len() {
+            let op = vm.ops[ip];
+            ip = (op.handler)(&mut vm, &op, ip);
+            if let Some(value) = vm.output.take() {
+                if !sink(value) {
+                    break;
+                }
+            }
+        }
+        Registers {
+            a: vm.registers[0],
+            b: vm.registers[1],
+            c: vm.registers[2],
+            d: vm.registers[3],
+        }
+    }
+}
+
+pub struct Vm {
+    registers: [isize; 4],
+    source: Vec<Instruction>,
+    optimized: bool,
+    ops: Vec<Op>,
+    plain: Vec<Op>,
+    output: Option<isize>,
+}
+
+impl Vm {
+    fn value(&self, is_register: isize, value: isize) -> isize {
+        if is_register != 0 {
+            self.registers[value as usize]
+        } else {
+            value
+        }
+    }
+
+    // Runs the unfused instruction at ip, for fused loops whose counters
+    // would make the original loop behave differently.
+    fn fall_back(&mut self, ip: usize) -> usize {
+        let op = self.plain[ip];
+        (op.handler)(self, &op, ip)
+    }
+}
+
+fn compile_all(instructions: &[Instruction], optimized: bool) -> (Vec<Op>, Vec<Op>) {
+    let plain = compile(instructions);
+    let ops = if optimized {
+        compile(&optimizer::optimize(instructions))
+    } else {
+        plain.clone()
+    };
+    (ops, plain)
+}
+
+pub fn compile(instructions: &[Instruction]) -> Vec<Op> {
+    instructions.iter()
+        .enumerate()
+        .map(|(ip, instruction)| compile_one(ip, instruction))
+        .collect()
+}
+
+fn index(register: &Register) -> isize {
+    match *register {
+        Register::A => 0,
+        Register::B => 1,
+        Register::C => 2,
+        Register::D => 3,
+    }
+}
+
+fn operand(operand: &Operand) -> (isize, isize) {
+    match *operand {
+        Operand::Register(ref register) => (1, index(register)),
+        Operand::Literal(value) => (0, value),
+    }
+}
+
+fn op(handler: Handler, x: isize, y: isize, z: isize, w: isize) -> Op {
+    Op { handler, x, y, z, w }
+}
+
+fn compile_one(ip: usize, instruction: &Instruction) -> Op {
+    match *instruction {
+        Instruction::INC(Operand::Register(ref r)) => op(inc, index(r), 0, 0, 0),
+        Instruction::DEC(Operand::Register(ref r)) => op(dec, index(r), 0, 0, 0),
+        Instruction::CPY(Operand::Literal(value), Operand::Register(ref r)) => op(cpy_literal, value, index(r), 0, 0),
+        Instruction::CPY(Operand::Register(ref src), Operand::Register(ref dst)) => op(cpy_register, index(src), index(dst), 0, 0),
+        Instruction::INC(_) | Instruction::DEC(_) | Instruction::CPY(..) | Instruction::MUL(_, Operand::Literal(_)) => op(skip, 0, 0, 0, 0),
+        Instruction::JNZ(value, Operand::Literal(offset)) => {
+            let target = ip as isize + offset;
+            let target = if target < 0 { 0 } else { target };
+            match value {
+                Operand::Literal(0) => op(skip, 0, 0, 0, 0),
+                Operand::Literal(_) => op(jump, target, 0, 0, 0),
+                Operand::Register(ref r) => op(jump_non_zero, index(r), target, 0, 0),
+            }
+        },
+        Instruction::JNZ(value, offset) => {
+            let (value_kind, value) = operand(&value);
+            let (offset_kind, offset) = operand(&offset);
+            op(jump_dynamic, value_kind, value, offset_kind, offset)
+        },
+        Instruction::TGL(offset) => {
+            let (kind, offset) = operand(&offset);
+            op(toggle, kind, offset, 0, 0)
+        },
+        Instruction::OUT(value) => {
+            let (kind, value) = operand(&value);
+            op(out, kind, value, 0, 0)
+        },
+        Instruction::MUL(factor, Operand::Register(ref r)) => {
+            let (kind, factor) = operand(&factor);
+            op(multiply, kind, factor, index(r), 0)
+        },
+        Instruction::ADD(ref counter, ref target) => op(add, index(counter), index(target), 0, 0),
+        Instruction::MULADD(factor, ref inner, ref outer, ref target) => {
+            let (kind, factor) = operand(&factor);
+            op(multiply_add, kind, factor, index(outer), index(target) * 4 + index(inner))
+        },
+        Instruction::ZERO(ref counter) => op(zero, index(counter), 0, 0, 0),
+    }
+}
+
+fn inc(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    vm.registers[op.x as usize] += 1;
+    ip + 1
+}
+
+fn dec(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    vm.registers[op.x as usize] -= 1;
+    ip + 1
+}
+
+fn cpy_literal(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    vm.registers[op.y as usize] = op.x;
+    ip + 1
+}
+
+fn cpy_register(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    vm.registers[op.y as usize] = vm.registers[op.x as usize];
+    ip + 1
+}
+
+fn skip(_: &mut Vm, _: &Op, ip: usize) -> usize {
+    ip + 1
+}
+
+fn jump(_: &mut Vm, op: &Op, _: usize) -> usize {
+    op.x as usize
+}
+
+fn jump_non_zero(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    if vm.registers[op.x as usize] != 0 {
+        op.y as usize
+    } else {
+        ip + 1
+    }
+}
+
+fn jump_dynamic(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    if vm.value(op.x, op.y) != 0 {
+        let target = ip as isize + vm.value(op.z, op.w);
+        if target < 0 { 0 } else { target as usize }
+    } else {
+        ip + 1
+    }
+}
+
+fn toggle(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    let target = ip as isize + vm.value(op.x, op.y);
+    if target >= 0 && (target as usize) < vm.source.len() {
+        let toggled = vm.source[target as usize].toggled();
+        vm.source[target as usize] = toggled;
+        let (ops, plain) = compile_all(&vm.source, vm.optimized);
+        vm.ops = ops;
+        vm.plain = plain;
+    }
+    ip + 1
+}
+
+fn out(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    vm.output = Some(vm.value(op.x, op.y));
+    ip + 1
+}
+
+fn multiply(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    vm.registers[op.z as usize] *= vm.value(op.x, op.y);
+    ip + 1
+}
+
+fn add(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    let counter = vm.registers[op.x as usize];
+    if counter <= 0 {
+        return vm.fall_back(ip);
+    }
+    vm.registers[op.y as usize] += counter;
+    vm.registers[op.x as usize] = 0;
+    ip + 3
+}
+
+fn multiply_add(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    let factor = vm.value(op.x, op.y);
+    let outer = vm.registers[op.z as usize];
+    if factor <= 0 || outer <= 0 {
+        return vm.fall_back(ip);
+    }
+    let (target, inner) = ((op.w / 4) as usize, (op.w % 4) as usize);
+    vm.registers[target] += factor * outer;
+    vm.registers[inner] = 0;
+    vm.registers[op.z as usize] = 0;
+    ip + 6
+}
+
+fn zero(vm: &mut Vm, op: &Op, ip: usize) -> usize {
+    if vm.registers[op.x as usize] <= 0 {
+        return vm.fall_back(ip);
+    }
+    vm.registers[op.x as usize] = 0;
+    ip + 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assembunny::Machine;
+    use parse::parse_lines;
+
+    const DAY12: &str = "cpy 1 a
+        cpy 1 b
+        cpy 26 d
+        jnz c 2
+        jnz 1 5
+        cpy 7 c
+        inc d
+        dec c
+        jnz c -2
+        cpy a c
+        inc a
+        dec b
+        jnz b -2
+        cpy c b
+        dec d
+        jnz d -6
+        cpy 13 c
+        cpy 14 d
+        inc a
+        dec d
+        jnz d -2
+        dec c
+        jnz c -5";
+
+    fn assert_matches_machine(input: &str, registers: Registers) {
+        let instructions: Vec<Instruction> = parse_lines(input).unwrap();
+        let mut machine = Machine::with_registers(registers);
+        machine.load_instructions(instructions.clone());
+        let expected = machine.run();
+        for optimized in [false, true].iter() {
+            let program = Program::compile(&instructions, *optimized);
+            assert_eq!(expected, program.run(registers), "optimized: {}", optimized);
+        }
+    }
+
+    #[test]
+    fn it_matches_the_machine_on_day12_examples() {
+        assert_matches_machine("inc a", Registers::default());
+        assert_matches_machine("dec b", Registers::default());
+        assert_matches_machine("cpy -2 c", Registers::default());
+        assert_matches_machine("cpy -2 c\ncpy c d", Registers::default());
+        assert_matches_machine("cpy 41 a\ninc a\ninc a\ndec a\njnz a 2\ndec a", Registers::default());
+    }
+
+    #[test]
+    fn it_matches_the_machine_on_day12() {
+        assert_matches_machine(DAY12, Registers::default());
+        assert_matches_machine(DAY12, Registers { c: 1, ..Registers::default() });
+    }
+
+    #[test]
+    fn it_matches_the_machine_on_extensions() {
+        assert_matches_machine("cpy 2 a\ntgl a\ntgl a\ntgl a\ncpy 1 a\ndec a\ndec a", Registers::default());
+        assert_matches_machine("cpy 6 a\ncpy 7 b\nmul a b\ncpy 2 3\ncpy 2 c\njnz 1 c\ninc d\ninc a", Registers::default());
+        assert_matches_machine("cpy a b\ndec b\ncpy a d\ncpy 0 a\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5", Registers { a: 7, ..Registers::default() });
+    }
+
+    #[test]
+    fn it_streams_output() {
+        let instructions: Vec<Instruction> = parse_lines("out 0\nout 1\njnz 1 -2").unwrap();
+        let program = Program::compile(&instructions, true);
+        let mut output = vec![];
+        program.run_with_output(Registers::default(), |value| {
+            output.push(value);
+            output.len() < 4
+        });
+        assert_eq!(vec![0, 1, 0, 1], output);
+    }
+}
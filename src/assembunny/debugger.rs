@@ -0,0 +1,250 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use assembunny::{Instruction, Machine, Register, Registers, Step};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Stop {
+    Stepped,
+    Halted,
+    Breakpoint(usize),
+    Watchpoint(Register, isize, isize),
+}
+
+impl fmt::Display for Stop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Stop::Stepped => write!(f, "stepped"),
+            Stop::Halted => write!(f, "halted"),
+            Stop::Breakpoint(ip) => write!(f, "breakpoint at {}", ip),
+            Stop::Watchpoint(register, old, new) => write!(f, "{} changed from {} to {}", register, old, new),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TraceEntry {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub registers: Registers,
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:>4}  {:<16}  {}", self.ip, self.instruction.to_string(), self.registers)
+    }
+}
+
+pub struct Debugger {
+    machine: Machine,
+    breakpoints: HashSet<usize>,
+    watchpoints: HashSet<Register>,
+    counts: Vec<usize>,
+    tracing: bool,
+    trace: Vec<TraceEntry>,
+    output: Vec<isize>,
+}
+
+impl Debugger {
+    // Fused loops would run several instructions as one, so breakpoints and
+    // counts inside them would never be hit. The debugger always runs the
+    // program as written.
+    pub fn new(mut machine: Machine) -> Debugger {
+        machine.set_optimized(false);
+        let counts = vec![0; machine.program().len()];
+        Debugger {
+            machine,
+            breakpoints: HashSet::new(),
+            watchpoints: HashSet::new(),
+            counts,
+            tracing: false,
+            trace: vec![],
+            output: vec![],
+        }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    pub fn remove_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.remove(&ip);
+    }
+
+    pub fn watch(&mut self, register: Register) {
+        self.watchpoints.insert(register);
+    }
+
+    pub fn unwatch(&mut self, register: Register) {
+        self.watchpoints.remove(&register);
+    }
+
+    pub fn set_register(&mut self, register: &Register, value: isize) {
+        self.machine.registers.set(register, value);
+    }
+
+    pub fn set_tracing(&mut self, tracing: bool) {
+        self.tracing = tracing;
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracing
+    }
+
+    pub fn trace(&self) -> &[TraceEntry] {
+        &self.trace
+    }
+
+    pub fn take_trace(&mut self) -> Vec<TraceEntry> {
+        self.trace.split_off(0)
+    }
+
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    pub fn output(&self) -> &[isize] {
+        &self.output
+    }
+
+    pub fn step(&mut self) -> Stop {
+        let instruction = self.machine.current_instruction();
+        let ip = self.machine.ip();
+        let before = self.machine.registers;
+
+        match self.machine.step() {
+            Step::Halted => return Stop::Halted,
+            Step::Output(value) => self.output.push(value),
+            Step::Ran => (),
+        }
+        self.counts[ip] += 1;
+
+        let after = self.machine.registers;
+        if let Some(instruction) = instruction.filter(|_| self.tracing) {
+            self.trace.push(TraceEntry {
+                ip,
+                instruction,
+                registers: after,
+            });
+        }
+
+        let mut watched = self.watchpoints.iter()
+            .filter(|register| before.get(register) != after.get(register))
+            .collect::<Vec<_>>();
+        watched.sort_by_key(|register| **register as usize);
+        if let Some(register) = watched.first() {
+            return Stop::Watchpoint(**register, before.get(register), after.get(register));
+        }
+        if self.machine.is_halted() {
+            Stop::Halted
+        } else {
+            Stop::Stepped
+        }
+    }
+
+    // Always executes at least one instruction, so resuming from a
+    // breakpoint moves past it.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            match self.step() {
+                Stop::Stepped => (),
+                stop => return stop,
+            }
+            let ip = self.machine.ip();
+            if self.breakpoints.contains(&ip) {
+                return Stop::Breakpoint(ip);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assembunny::Operand;
+
+    fn debugger(input: &str) -> Debugger {
+        let mut machine = Machine::new();
+        machine.set_optimized(false);
+        machine.load(input).unwrap();
+        Debugger::new(machine)
+    }
+
+    #[test]
+    fn it_single_steps() {
+        let mut debugger = debugger("inc a\ninc a");
+        assert_eq!(Stop::Stepped, debugger.step());
+        assert_eq!(1, debugger.machine().registers.a);
+        assert_eq!(Stop::Halted, debugger.step());
+        assert_eq!(Stop::Halted, debugger.step());
+        assert_eq!(2, debugger.machine().registers.a);
+    }
+
+    #[test]
+    fn it_stops_at_breakpoints() {
+        let mut debugger = debugger("cpy 3 b\ninc a\ndec b\njnz b -2\ninc c");
+        debugger.add_breakpoint(2);
+        assert_eq!(Stop::Breakpoint(2), debugger.resume());
+        assert_eq!(1, debugger.machine().registers.a);
+        assert_eq!(Stop::Breakpoint(2), debugger.resume());
+        assert_eq!(2, debugger.machine().registers.a);
+        debugger.remove_breakpoint(2);
+        assert_eq!(Stop::Halted, debugger.resume());
+        assert_eq!(1, debugger.machine().registers.c);
+    }
+
+    #[test]
+    fn it_stops_at_watchpoints() {
+        let mut debugger = debugger("inc a\ncpy 5 c\ninc b");
+        debugger.watch(Register::C);
+        assert_eq!(Stop::Watchpoint(Register::C, 0, 5), debugger.resume());
+        assert_eq!(2, debugger.machine().ip());
+        debugger.unwatch(Register::C);
+        assert_eq!(Stop::Halted, debugger.resume());
+    }
+
+    #[test]
+    fn it_counts_executions() {
+        let mut debugger = debugger("cpy 3 b\ninc a\ndec b\njnz b -2");
+        debugger.resume();
+        assert_eq!(&[1, 3, 3, 3], debugger.counts());
+    }
+
+    #[test]
+    fn it_traces_steps() {
+        let mut debugger = debugger("cpy 2 a\ndec a");
+        debugger.set_tracing(true);
+        debugger.resume();
+        let cpy = Instruction::CPY(Operand::Literal(2), Operand::Register(Register::A));
+        let expected = vec![
+            TraceEntry { ip: 0, instruction: cpy, registers: Registers { a: 2, b: 0, c: 0, d: 0 } },
+            TraceEntry { ip: 1, instruction: Instruction::DEC(Operand::Register(Register::A)), registers: Registers { a: 1, b: 0, c: 0, d: 0 } },
+        ];
+        assert_eq!(expected, debugger.take_trace());
+        assert!(debugger.trace().is_empty());
+    }
+
+    #[test]
+    fn it_collects_output() {
+        let mut debugger = debugger("out 1\nout 2");
+        debugger.resume();
+        assert_eq!(&[1, 2], debugger.output());
+    }
+
+    #[test]
+    fn it_runs_fused_loops_one_instruction_at_a_time() {
+        let mut machine = Machine::new();
+        machine.load("cpy 3 b\ninc a\ndec b\njnz b -2\ninc c").unwrap();
+        let mut debugger = Debugger::new(machine);
+        debugger.add_breakpoint(2);
+        assert_eq!(Stop::Breakpoint(2), debugger.resume());
+        assert_eq!(1, debugger.machine().registers.a);
+        debugger.remove_breakpoint(2);
+        assert_eq!(Stop::Halted, debugger.resume());
+        assert_eq!(&[1, 3, 3, 3, 1], debugger.counts());
+    }
+}
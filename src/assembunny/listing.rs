@@ -0,0 +1,162 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use assembunny::{Instruction, Operand};
+
+pub struct Listing {
+    pub instructions: Vec<Instruction>,
+    pub labels: BTreeMap<usize, String>,
+    pub blocks: Vec<usize>,
+    pub loops: Vec<(usize, usize)>,
+}
+
+impl Listing {
+    pub fn new(instructions: &[Instruction]) -> Listing {
+        let len = instructions.len();
+        let mut targets = vec![];
+        let mut blocks = vec![0];
+        let mut loops = vec![];
+        for (ip, instruction) in instructions.iter().enumerate() {
+            if let Instruction::JNZ(..) = *instruction {
+                if ip + 1 < len {
+                    blocks.push(ip + 1);
+                }
+            }
+            if let Some(target) = jump_target(ip, instruction) {
+                if target > len {
+                    continue;
+                }
+                targets.push(target);
+                if target < len {
+                    blocks.push(target);
+                }
+                if target <= ip {
+                    loops.push((target, ip));
+                }
+            }
+        }
+        targets.sort();
+        targets.dedup();
+        blocks.sort();
+        blocks.dedup();
+        loops.sort();
+
+        let mut labels = BTreeMap::new();
+        for (index, target) in targets.into_iter().enumerate() {
+            let label = if target == len {
+                "end".to_string()
+            } else {
+                format!("L{}", index)
+            };
+            labels.insert(target, label);
+        }
+
+        Listing {
+            instructions: instructions.to_vec(),
+            labels,
+            blocks,
+            loops,
+        }
+    }
+
+    pub fn loop_depth(&self, ip: usize) -> usize {
+        self.loops.iter()
+            .filter(|&&(head, back_edge)| head <= ip && ip <= back_edge)
+            .count()
+    }
+
+    fn text(&self, ip: usize) -> String {
+        let instruction = &self.instructions[ip];
+        let label = jump_target(ip, instruction).and_then(|target| self.labels.get(&target));
+        match (*instruction, label) {
+            (Instruction::JNZ(value, _), Some(label)) => format!("jnz {} {}", value, label),
+            _ => instruction.to_string(),
+        }
+    }
+}
+
+// Mirrors Machine, which treats jumps before the start as jumps to it.
+pub fn jump_target(ip: usize, instruction: &Instruction) -> Option<usize> {
+    match *instruction {
+        Instruction::JNZ(_, Operand::Literal(offset)) => {
+            let target = ip as isize + offset;
+            Some(if target < 0 { 0 } else { target as usize })
+        },
+        _ => None,
+    }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for ip in 0..self.instructions.len() {
+            if ip != 0 && self.blocks.contains(&ip) {
+                writeln!(f)?;
+            }
+            if let Some(label) = self.labels.get(&ip) {
+                writeln!(f, "{}:", label)?;
+            }
+            let bars = "| ".repeat(self.loop_depth(ip));
+            writeln!(f, "{:>4}  {}{}", ip, bars, self.text(ip))?;
+        }
+        if let Some(label) = self.labels.get(&self.instructions.len()) {
+            writeln!(f, "{}:", label)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parse::parse_lines;
+
+    fn listing(input: &str) -> Listing {
+        Listing::new(&parse_lines::<Instruction>(input).unwrap())
+    }
+
+    #[test]
+    fn it_labels_jump_targets() {
+        let listing = listing("jnz c 2\ninc a\ninc b\njnz 1 -3");
+        assert_eq!(Some(&"L0".to_string()), listing.labels.get(&0));
+        assert_eq!(Some(&"L1".to_string()), listing.labels.get(&2));
+        assert_eq!(2, listing.labels.len());
+    }
+
+    #[test]
+    fn it_labels_the_end() {
+        let listing = listing("jnz a 2\ninc a");
+        assert_eq!(Some(&"end".to_string()), listing.labels.get(&2));
+    }
+
+    #[test]
+    fn it_finds_basic_blocks() {
+        let listing = listing("cpy 3 b\ninc a\ndec b\njnz b -2\ninc c");
+        assert_eq!(vec![0, 1, 4], listing.blocks);
+    }
+
+    #[test]
+    fn it_finds_nested_loops() {
+        let listing = listing("cpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5");
+        assert_eq!(vec![(0, 5), (1, 3)], listing.loops);
+        assert_eq!(1, listing.loop_depth(0));
+        assert_eq!(2, listing.loop_depth(2));
+    }
+
+    #[test]
+    fn it_prints_a_listing() {
+        let listing = listing("cpy 3 b\njnz c 4\ninc a\ndec b\njnz b -2\ninc c\njnz a c");
+        let expected = "   0  cpy 3 b
+   1  jnz c L1
+
+L0:
+   2  | inc a
+   3  | dec b
+   4  | jnz b L0
+
+L1:
+   5  inc c
+   6  jnz a c
+";
+        assert_eq!(expected, listing.to_string());
+    }
+}
@@ -0,0 +1,696 @@
+use std::fmt;
+use std::str::FromStr;
+use parse::{parse_lines, ParseError, Tokens};
+
+pub mod bytecode;
+pub mod debugger;
+pub mod listing;
+pub mod optimizer;
+
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Registers {
+    pub a: isize,
+    pub b: isize,
+    pub c: isize,
+    pub d: isize,
+}
+
+impl Registers {
+    pub fn get(&self, register: &Register) -> isize {
+        match *register {
+            Register::A => self.a,
+            Register::B => self.b,
+            Register::C => self.c,
+            Register::D => self.d,
+        }
+    }
+
+    pub fn set(&mut self, register: &Register, value: isize) {
+        match *register {
+            Register::A => self.a = value,
+            Register::B => self.b = value,
+            Register::C => self.c = value,
+            Register::D => self.d = value,
+        }
+    }
+}
+
+impl fmt::Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a={} b={} c={} d={}", self.a, self.b, self.c, self.d)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct StepLimit {
+    pub steps: usize,
+    pub registers: Registers,
+}
+
+impl fmt::Display for StepLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "still running after {} steps with {}", self.steps, self.registers)
+    }
+}
+
+// What running one instruction did.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Step {
+    Ran,
+    Output(isize),
+    Halted,
+}
+
+pub struct Machine {
+    pub registers: Registers,
+    instructions: Vec<Instruction>,
+    program: Vec<Instruction>,
+    optimized: bool,
+    ip: usize,
+    steps: usize,
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Machine {
+    pub fn new() -> Machine {
+        Machine::with_registers(Registers::default())
+    }
+
+    pub fn with_registers(registers: Registers) -> Machine {
+        Machine{
+            registers,
+            instructions: vec![],
+            program: vec![],
+            optimized: true,
+            ip: 0,
+            steps: 0,
+        }
+    }
+
+    pub fn load(&mut self, input: &str) -> Result<(), ParseError> {
+        self.load_instructions(parse_lines(input)?);
+        Ok(())
+    }
+
+    pub fn load_instructions(&mut self, instructions: Vec<Instruction>) {
+        self.instructions = instructions;
+        self.rebuild_program();
+    }
+
+    pub fn set_optimized(&mut self, optimized: bool) {
+        self.optimized = optimized;
+        self.rebuild_program();
+    }
+
+    pub fn compile(&self) -> bytecode::Program {
+        bytecode::Program::compile(&self.instructions, self.optimized)
+    }
+
+    fn rebuild_program(&mut self) {
+        self.program = if self.optimized {
+            optimizer::optimize(&self.instructions)
+        } else {
+            self.instructions.clone()
+        };
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    pub fn current_instruction(&self) -> Option<Instruction> {
+        if self.is_halted() {
+            return None;
+        }
+        let instruction = self.program[self.ip];
+        if self.can_fuse(&instruction) {
+            Some(instruction)
+        } else {
+            Some(self.instructions[self.ip])
+        }
+    }
+
+    // Does nothing once the program has halted.
+    pub fn step(&mut self) -> Step {
+        let instruction = match self.current_instruction() {
+            Some(instruction) => instruction,
+            None => return Step::Halted,
+        };
+        let mut step = Step::Ran;
+        match instruction {
+            Instruction::INC(reg) => self.add(&reg, 1),
+            Instruction::DEC(reg) => self.add(&reg, -1),
+            Instruction::CPY(src, dst) => self.copy(&src, &dst),
+            Instruction::JNZ(value, jump) => self.jump_non_zero(&value, &jump),
+            Instruction::TGL(offset) => self.toggle(&offset),
+            Instruction::OUT(value) => {
+                step = Step::Output(self.value(&value));
+                self.ip += 1;
+            },
+            Instruction::MUL(factor, dst) => self.multiply(&factor, &dst),
+            Instruction::ADD(counter, target) => {
+                let value = self.fetch(&target) + self.fetch(&counter);
+                self.registers.set(&target, value);
+                self.registers.set(&counter, 0);
+                self.ip += 3;
+            },
+            Instruction::MULADD(factor, inner, outer, target) => {
+                let value = self.fetch(&target) + self.value(&factor) * self.fetch(&outer);
+                self.registers.set(&target, value);
+                self.registers.set(&inner, 0);
+                self.registers.set(&outer, 0);
+                self.ip += 6;
+            },
+            Instruction::ZERO(counter) => {
+                self.registers.set(&counter, 0);
+                self.ip += 2;
+            },
+        }
+        self.steps += 1;
+        step
+    }
+
+    // The fused loops only terminate when their counters start positive, so
+    // anything else runs the original instruction instead.
+    fn can_fuse(&self, instruction: &Instruction) -> bool {
+        match *instruction {
+            Instruction::ADD(counter, _) | Instruction::ZERO(counter) => self.fetch(&counter) > 0,
+            Instruction::MULADD(factor, _, outer, _) => self.value(&factor) > 0 && self.fetch(&outer) > 0,
+            _ => true,
+        }
+    }
+
+    pub fn add(&mut self, operand: &Operand, amount: isize) {
+        if let Operand::Register(ref register) = *operand {
+            let value = self.fetch(register);
+            self.registers.set(register, value + amount);
+        }
+        self.ip += 1;
+    }
+
+    pub fn copy(&mut self, src: &Operand, dst: &Operand) {
+        if let Operand::Register(ref register) = *dst {
+            let value = self.value(src);
+            self.registers.set(register, value);
+        }
+        self.ip += 1;
+    }
+
+    pub fn multiply(&mut self, factor: &Operand, dst: &Operand) {
+        if let Operand::Register(ref register) = *dst {
+            let value = self.value(factor) * self.fetch(register);
+            self.registers.set(register, value);
+        }
+        self.ip += 1;
+    }
+
+    pub fn jump_non_zero(&mut self, value: &Operand, jump: &Operand) {
+        if self.value(value) != 0 {
+            let new = (self.ip as isize) + self.value(jump);
+            if new < 0 {
+                self.ip = 0;
+            } else {
+                self.ip = new as usize;
+            }
+        } else {
+            self.ip += 1;
+        }
+    }
+
+    pub fn toggle(&mut self, offset: &Operand) {
+        let target = (self.ip as isize) + self.value(offset);
+        if target >= 0 && (target as usize) < self.instructions.len() {
+            let toggled = self.instructions[target as usize].toggled();
+            self.instructions[target as usize] = toggled;
+            self.rebuild_program();
+        }
+        self.ip += 1;
+    }
+
+    pub fn fetch(&self, register: &Register) -> isize {
+        self.registers.get(register)
+    }
+
+    pub fn value(&self, operand: &Operand) -> isize {
+        match *operand {
+            Operand::Register(ref register) => self.fetch(register),
+            Operand::Literal(value) => value,
+        }
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.instructions.len()
+    }
+
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn run(&mut self) -> Registers {
+        self.run_with_output(|_| true)
+    }
+
+    pub fn run_with_output<F: FnMut(isize) -> bool>(&mut self, sink: F) -> Registers {
+        match self.execute(None, sink) {
+            Ok(registers) => registers,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    pub fn run_limited(&mut self, max_steps: usize) -> Result<Registers, StepLimit> {
+        self.execute(Some(max_steps), |_| true)
+    }
+
+    // Runs until the program halts, the step limit is reached, or the sink
+    // returns false to stop after the value it was given.
+    pub fn execute<F: FnMut(isize) -> bool>(&mut self, max_steps: Option<usize>, mut sink: F) -> Result<Registers, StepLimit> {
+        while !self.is_halted() {
+            if max_steps.is_some_and(|max_steps| self.steps >= max_steps) {
+                return Err(StepLimit {
+                    steps: self.steps,
+                    registers: self.registers,
+                });
+            }
+            if let Step::Output(value) = self.step() {
+                if !sink(value) {
+                    break;
+                }
+            }
+        }
+        Ok(self.registers)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Register {
+    A,
+    B,
+    C,
+    D
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Register::A => write!(f, "a"),
+            Register::B => write!(f, "b"),
+            Register::C => write!(f, "c"),
+            Register::D => write!(f, "d"),
+        }
+    }
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "a" => Ok(Register::A),
+            "b" => Ok(Register::B),
+            "c" => Ok(Register::C),
+            "d" => Ok(Register::D),
+            _ => Err(ParseError::new(1, s, "a register")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Operand {
+    Register(Register),
+    Literal(isize),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Literal(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl FromStr for Operand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(register) => Ok(Operand::Register(register)),
+            Err(_) => s.parse()
+                .map(Operand::Literal)
+                .map_err(|_| ParseError::new(1, s, "a register or number")),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Instruction {
+    INC(Operand),
+    DEC(Operand),
+    CPY(Operand, Operand),
+    JNZ(Operand, Operand),
+    TGL(Operand),
+    OUT(Operand),
+    MUL(Operand, Operand),
+    ADD(Register, Register),
+    MULADD(Operand, Register, Register, Register),
+    ZERO(Register),
+}
+
+impl Instruction {
+    pub fn toggled(&self) -> Instruction {
+        match *self {
+            Instruction::INC(x) => Instruction::DEC(x),
+            Instruction::DEC(x) | Instruction::TGL(x) | Instruction::OUT(x) => Instruction::INC(x),
+            Instruction::JNZ(x, y) => Instruction::CPY(x, y),
+            Instruction::CPY(x, y) | Instruction::MUL(x, y) => Instruction::JNZ(x, y),
+            Instruction::ADD(..) | Instruction::MULADD(..) | Instruction::ZERO(..) => *self,
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Instruction::INC(x) => write!(f, "inc {}", x),
+            Instruction::DEC(x) => write!(f, "dec {}", x),
+            Instruction::CPY(x, y) => write!(f, "cpy {} {}", x, y),
+            Instruction::JNZ(x, y) => write!(f, "jnz {} {}", x, y),
+            Instruction::TGL(x) => write!(f, "tgl {}", x),
+            Instruction::OUT(x) => write!(f, "out {}", x),
+            Instruction::MUL(x, y) => write!(f, "mul {} {}", x, y),
+            Instruction::ADD(counter, target) => write!(f, "add {} {}", counter, target),
+            Instruction::MULADD(factor, inner, outer, target) => write!(f, "muladd {} {} {} {}", factor, inner, outer, target),
+            Instruction::ZERO(x) => write!(f, "zero {}", x),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = Tokens::new(s);
+        let opcode = tokens.next_token("an instruction")?;
+        let mut operand = |expected| -> Result<Operand, ParseError> {
+            tokens.next_token(expected)?.parse(expected)
+        };
+        let instruction = match opcode.text {
+            "inc" => Instruction::INC(operand("a register")?),
+            "dec" => Instruction::DEC(operand("a register")?),
+            "cpy" => Instruction::CPY(operand("a register or number")?, operand("a register")?),
+            "jnz" => Instruction::JNZ(operand("a register or number")?, operand("a jump offset")?),
+            "tgl" => Instruction::TGL(operand("an offset")?),
+            "out" => Instruction::OUT(operand("a register or number")?),
+            "mul" => Instruction::MUL(operand("a register or number")?, operand("a register")?),
+            _ => return Err(opcode.error("one of cpy, inc, dec, jnz, tgl, out or mul")),
+        };
+        tokens.finish()?;
+        Ok(instruction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_increments() {
+        assert_eq!(Instruction::INC(Operand::Register(Register::A)), "inc a".parse().unwrap());
+    }
+
+    #[test]
+    fn it_parses_decrement() {
+        assert_eq!(Instruction::DEC(Operand::Register(Register::A)), "dec a".parse().unwrap());
+    }
+
+    #[test]
+    fn it_parses_jnz_register() {
+        assert_eq!(Instruction::JNZ(Operand::Register(Register::B), Operand::Literal(-1)), "jnz b -1".parse().unwrap());
+    }
+
+    #[test]
+    fn it_parses_jnz_literal() {
+        assert_eq!(Instruction::JNZ(Operand::Literal(10), Operand::Literal(-1)), "jnz 10 -1".parse().unwrap());
+    }
+
+    #[test]
+    fn it_parses_cpy_literal() {
+        assert_eq!(Instruction::CPY(Operand::Literal(41), Operand::Register(Register::C)), "cpy 41 c".parse().unwrap());
+    }
+
+    #[test]
+    fn it_parses_cpy_register() {
+        assert_eq!(Instruction::CPY(Operand::Register(Register::A), Operand::Register(Register::D)), "cpy a d".parse().unwrap());
+    }
+
+    #[test]
+    fn it_parses_operands_in_any_position() {
+        assert_eq!(Instruction::JNZ(Operand::Literal(1), Operand::Register(Register::C)), "jnz 1 c".parse().unwrap());
+        assert_eq!(Instruction::CPY(Operand::Literal(2), Operand::Literal(3)), "cpy 2 3".parse().unwrap());
+    }
+
+    #[test]
+    fn it_parses_extensions() {
+        assert_eq!(Instruction::TGL(Operand::Register(Register::A)), "tgl a".parse().unwrap());
+        assert_eq!(Instruction::OUT(Operand::Literal(0)), "out 0".parse().unwrap());
+        assert_eq!(Instruction::MUL(Operand::Register(Register::B), Operand::Register(Register::A)), "mul b a".parse().unwrap());
+    }
+
+    #[test]
+    fn it_prints_instructions_that_parse_back() {
+        let lines = ["inc a", "dec b", "cpy 41 c", "cpy a d", "cpy 2 3", "jnz b -2", "jnz 1 c", "tgl a", "out 0", "mul b a"];
+        for line in lines.iter() {
+            let instruction: Instruction = line.parse().unwrap();
+            assert_eq!(*line, instruction.to_string());
+            assert_eq!(instruction, instruction.to_string().parse().unwrap());
+        }
+    }
+
+    #[test]
+    fn it_prints_fused_instructions() {
+        assert_eq!("add b a", Instruction::ADD(Register::B, Register::A).to_string());
+        assert_eq!("zero c", Instruction::ZERO(Register::C).to_string());
+    }
+
+    #[test]
+    fn it_toggles_instructions() {
+        let a = Operand::Register(Register::A);
+        let one = Operand::Literal(1);
+        assert_eq!(Instruction::DEC(a), Instruction::INC(a).toggled());
+        assert_eq!(Instruction::INC(a), Instruction::DEC(a).toggled());
+        assert_eq!(Instruction::INC(a), Instruction::TGL(a).toggled());
+        assert_eq!(Instruction::INC(a), Instruction::OUT(a).toggled());
+        assert_eq!(Instruction::CPY(one, a), Instruction::JNZ(one, a).toggled());
+        assert_eq!(Instruction::JNZ(one, a), Instruction::CPY(one, a).toggled());
+        assert_eq!(Instruction::JNZ(one, a), Instruction::MUL(one, a).toggled());
+    }
+
+    #[test]
+    fn it_reports_unknown_instructions() {
+        let error = "add a b".parse::<Instruction>().unwrap_err();
+        assert_eq!(ParseError::new(1, "add", "one of cpy, inc, dec, jnz, tgl, out or mul"), error);
+    }
+
+    #[test]
+    fn it_reports_bad_operands() {
+        let error = "cpy 41 e".parse::<Instruction>().unwrap_err();
+        assert_eq!(ParseError::new(8, "e", "a register"), error);
+        let error = "jnz a".parse::<Instruction>().unwrap_err();
+        assert_eq!(ParseError::new(6, "", "a jump offset"), error);
+    }
+
+    #[test]
+    fn machine_reports_the_bad_line() {
+        let mut machine = Machine::new();
+        let error = machine.load("inc a\ninc a b").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(7, error.column);
+    }
+
+    #[test]
+    fn machine_evals_increment_instruction() {
+        let mut machine = Machine::new();
+        machine.load("inc a").unwrap();
+        machine.step();
+        assert_eq!(machine.registers.a, 1);
+    }
+
+    #[test]
+    fn machine_does_nothing_once_halted() {
+        let mut machine = Machine::new();
+        machine.load("out 3").unwrap();
+        assert_eq!(Step::Output(3), machine.step());
+        assert_eq!(Step::Halted, machine.step());
+        assert_eq!(1, machine.steps());
+        assert_eq!(1, machine.ip());
+    }
+
+    #[test]
+    fn machine_evals_decrement_instruction() {
+        let mut machine = Machine::new();
+        machine.load("dec b").unwrap();
+        machine.step();
+        assert_eq!(machine.registers.b, -1);
+    }
+
+    #[test]
+    fn machine_evals_cpyl() {
+        let mut machine = Machine::new();
+        machine.load("cpy -2 c").unwrap();
+        machine.step();
+        assert_eq!(machine.registers.c, -2);
+    }
+
+    #[test]
+    fn machine_evals_cpyr() {
+        let mut machine = Machine::new();
+        machine.load("cpy -2 c\ncpy c d").unwrap();
+        machine.step();
+        machine.step();
+        assert_eq!(machine.registers.d, -2);
+    }
+
+    #[test]
+    fn machine_evals_jnz_zero_register() {
+        let mut machine = Machine::new();
+        machine.load("jnz a 4").unwrap();
+        machine.step();
+        assert_eq!(machine.ip, 1);
+    }
+
+    #[test]
+    fn machine_evals_jnz_zero_literal() {
+        let mut machine = Machine::new();
+        machine.load("jnz 0 4").unwrap();
+        machine.step();
+        assert_eq!(machine.ip, 1);
+    }
+
+    #[test]
+    fn machine_evals_jnz_non_zero_forward() {
+        let mut machine = Machine::new();
+        machine.load("inc a\njnz a 4").unwrap();
+        machine.step();
+        machine.step();
+        assert_eq!(machine.ip, 5);
+    }
+
+    #[test]
+    fn machine_evals_jnz_non_zero_backwards() {
+        let mut machine = Machine::new();
+        machine.load("inc a\njnz a -4").unwrap();
+        machine.step();
+        machine.step();
+        assert_eq!(machine.ip, 0);
+    }
+
+    #[test]
+    fn machine_runs_self_modifying_code() {
+        let mut machine = Machine::new();
+        machine.load("cpy 2 a
+        tgl a
+        tgl a
+        tgl a
+        cpy 1 a
+        dec a
+        dec a").unwrap();
+        assert_eq!(3, machine.run().a);
+    }
+
+    #[test]
+    fn machine_ignores_toggles_out_of_range() {
+        let mut machine = Machine::new();
+        machine.load("tgl 5\ninc a").unwrap();
+        assert_eq!(1, machine.run().a);
+    }
+
+    #[test]
+    fn machine_skips_copies_into_literals() {
+        let mut machine = Machine::new();
+        machine.load("cpy 2 3\ninc a").unwrap();
+        assert_eq!(Registers { a: 1, b: 0, c: 0, d: 0 }, machine.run());
+    }
+
+    #[test]
+    fn machine_multiplies() {
+        let mut machine = Machine::new();
+        machine.load("cpy 6 a\ncpy 7 b\nmul a b").unwrap();
+        assert_eq!(42, machine.run().b);
+    }
+
+    #[test]
+    fn machine_streams_output() {
+        let mut machine = Machine::new();
+        machine.load("cpy 3 a\nout a\ndec a\njnz a -2").unwrap();
+        let mut output = vec![];
+        machine.run_with_output(|value| {
+            output.push(value);
+            true
+        });
+        assert_eq!(vec![3, 2, 1], output);
+    }
+
+    #[test]
+    fn machine_stops_when_the_sink_is_full() {
+        let mut machine = Machine::new();
+        machine.load("out 0\nout 1\njnz 1 -2").unwrap();
+        let mut output = vec![];
+        machine.run_with_output(|value| {
+            output.push(value);
+            output.len() < 5
+        });
+        assert_eq!(vec![0, 1, 0, 1, 0], output);
+    }
+
+    #[test]
+    fn machine_starts_with_given_registers() {
+        let mut machine = Machine::with_registers(Registers { a: 0, b: 0, c: 1, d: 0 });
+        machine.load("cpy c a").unwrap();
+        assert_eq!(Registers { a: 1, b: 0, c: 1, d: 0 }, machine.run());
+    }
+
+    #[test]
+    fn machine_stops_at_step_limit() {
+        let mut machine = Machine::new();
+        machine.load("inc a\njnz a -1").unwrap();
+        let limit = machine.run_limited(5).unwrap_err();
+        assert_eq!(5, limit.steps);
+        assert_eq!(3, limit.registers.a);
+    }
+
+    #[test]
+    fn machine_finishes_within_step_limit() {
+        let mut machine = Machine::new();
+        machine.set_optimized(false);
+        machine.load("cpy 3 b\ninc a\ndec b\njnz b -2").unwrap();
+        let registers = machine.run_limited(100).unwrap();
+        assert_eq!(Registers { a: 3, b: 0, c: 0, d: 0 }, registers);
+        assert_eq!(10, machine.steps());
+    }
+
+    #[test]
+    fn machine_runs_until_stop() {
+        let mut machine = Machine::new();
+        machine.load("cpy 41 a
+        inc a
+        inc a
+        dec a
+        jnz a 2
+        dec a").unwrap();
+        assert_eq!(42, machine.run().a);
+        assert_eq!(machine.registers.a, 42);
+    }
+}
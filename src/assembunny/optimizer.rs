@@ -0,0 +1,211 @@
+use assembunny::{Instruction, Operand, Register};
+
+// Fused instructions replace the first instruction of the loop they stand
+// for and leave the rest in place, so jump offsets into and out of the loop
+// still line up.
+pub fn optimize(instructions: &[Instruction]) -> Vec<Instruction> {
+    let mut optimized = instructions.to_vec();
+    for ip in 0..instructions.len() {
+        let rest = &instructions[ip..];
+        if let Some(fused) = multiply_into(rest).or_else(|| add_into(rest)).or_else(|| zero_out(rest)) {
+            optimized[ip] = fused;
+        }
+    }
+    optimized
+}
+
+// inc a / dec b / jnz b -2, in either order
+pub fn add_into(instructions: &[Instruction]) -> Option<Instruction> {
+    if instructions.len() < 3 || !jumps_back(&instructions[2], -2) {
+        return None;
+    }
+    let counter = jump_register(&instructions[2])?;
+    let (first, second) = (&instructions[0], &instructions[1]);
+    let target = match (*first, *second) {
+        (Instruction::INC(target), Instruction::DEC(Operand::Register(dec))) if dec == counter => target,
+        (Instruction::DEC(Operand::Register(dec)), Instruction::INC(target)) if dec == counter => target,
+        _ => return None,
+    };
+    match target {
+        Operand::Register(target) if target != counter => Some(Instruction::ADD(counter, target)),
+        _ => None,
+    }
+}
+
+// cpy x c / <add c into a> / dec d / jnz d -5
+pub fn multiply_into(instructions: &[Instruction]) -> Option<Instruction> {
+    if instructions.len() < 6 || !jumps_back(&instructions[5], -5) {
+        return None;
+    }
+    let (factor, inner) = match instructions[0] {
+        Instruction::CPY(factor, Operand::Register(inner)) => (factor, inner),
+        _ => return None,
+    };
+    let (counter, target) = match add_into(&instructions[1..4]) {
+        Some(Instruction::ADD(counter, target)) => (counter, target),
+        _ => return None,
+    };
+    let outer = jump_register(&instructions[5])?;
+    if counter != inner || instructions[4] != Instruction::DEC(Operand::Register(outer)) {
+        return None;
+    }
+    if outer == inner || outer == target || factor == Operand::Register(inner) ||
+        factor == Operand::Register(outer) || factor == Operand::Register(target) {
+        return None;
+    }
+    Some(Instruction::MULADD(factor, inner, outer, target))
+}
+
+// dec a / jnz a -1
+pub fn zero_out(instructions: &[Instruction]) -> Option<Instruction> {
+    if instructions.len() < 2 || !jumps_back(&instructions[1], -1) {
+        return None;
+    }
+    let counter = jump_register(&instructions[1])?;
+    if instructions[0] == Instruction::DEC(Operand::Register(counter)) {
+        Some(Instruction::ZERO(counter))
+    } else {
+        None
+    }
+}
+
+fn jumps_back(instruction: &Instruction, offset: isize) -> bool {
+    match *instruction {
+        Instruction::JNZ(Operand::Register(_), Operand::Literal(jump)) => jump == offset,
+        _ => false,
+    }
+}
+
+fn jump_register(instruction: &Instruction) -> Option<Register> {
+    match *instruction {
+        Instruction::JNZ(Operand::Register(register), _) => Some(register),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assembunny::{Machine, Registers};
+    use parse::parse_lines;
+
+    fn program(input: &str) -> Vec<Instruction> {
+        parse_lines(input).unwrap()
+    }
+
+    fn run(input: &str, registers: Registers, optimized: bool) -> (Registers, usize) {
+        let mut machine = Machine::with_registers(registers);
+        machine.set_optimized(optimized);
+        machine.load(input).unwrap();
+        let registers = machine.run();
+        (registers, machine.steps())
+    }
+
+    #[test]
+    fn it_fuses_add_loops() {
+        let expected = Instruction::ADD(Register::B, Register::A);
+        assert_eq!(Some(expected), add_into(&program("inc a\ndec b\njnz b -2")));
+        assert_eq!(Some(expected), add_into(&program("dec b\ninc a\njnz b -2")));
+        assert_eq!(None, add_into(&program("inc a\ndec b\njnz a -2")));
+        assert_eq!(None, add_into(&program("inc b\ndec b\njnz b -2")));
+    }
+
+    #[test]
+    fn it_fuses_multiply_loops() {
+        let expected = Instruction::MULADD(Operand::Register(Register::B), Register::C, Register::D, Register::A);
+        assert_eq!(Some(expected), multiply_into(&program("cpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5")));
+        assert_eq!(None, multiply_into(&program("cpy d c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5")));
+    }
+
+    #[test]
+    fn it_fuses_zero_loops() {
+        assert_eq!(Some(Instruction::ZERO(Register::C)), zero_out(&program("dec c\njnz c -1")));
+        assert_eq!(None, zero_out(&program("inc c\njnz c -1")));
+    }
+
+    #[test]
+    fn it_keeps_program_length() {
+        let input = program("cpy 3 b\ninc a\ndec b\njnz b -2\ninc a");
+        let optimized = optimize(&input);
+        assert_eq!(input.len(), optimized.len());
+        assert_eq!(Instruction::ADD(Register::B, Register::A), optimized[1]);
+        assert_eq!(input[2..], optimized[2..]);
+    }
+
+    #[test]
+    fn it_matches_unoptimized_results() {
+        let input = "cpy 1 a
+        cpy 1 b
+        cpy 26 d
+        jnz c 2
+        jnz 1 5
+        cpy 7 c
+        inc d
+        dec c
+        jnz c -2
+        cpy a c
+        inc a
+        dec b
+        jnz b -2
+        cpy c b
+        dec d
+        jnz d -6
+        cpy 13 c
+        cpy 14 d
+        inc a
+        dec d
+        jnz d -2
+        dec c
+        jnz c -5";
+        for c in 0..2 {
+            let registers = Registers { c, ..Registers::default() };
+            let (slow, slow_steps) = run(input, registers, false);
+            let (fast, fast_steps) = run(input, registers, true);
+            assert_eq!(slow, fast);
+            assert!(fast_steps < slow_steps / 10);
+        }
+    }
+
+    #[test]
+    fn it_matches_unoptimized_multiplication() {
+        let input = "cpy a b
+        dec b
+        cpy a d
+        cpy 0 a
+        cpy b c
+        inc a
+        dec c
+        jnz c -2
+        dec d
+        jnz d -5
+        cpy 5 c
+        dec c
+        jnz c -1";
+        let registers = Registers { a: 12, ..Registers::default() };
+        assert_eq!(run(input, registers, false).0, run(input, registers, true).0);
+        assert_eq!(132, run(input, registers, true).0.a);
+    }
+
+    #[test]
+    fn it_falls_back_when_counters_are_not_positive() {
+        let input = "inc a\ndec b\njnz b -2";
+        let mut slow = Machine::new();
+        slow.set_optimized(false);
+        slow.load(input).unwrap();
+        let mut fast = Machine::new();
+        fast.load(input).unwrap();
+        assert_eq!(slow.run_limited(9), fast.run_limited(9));
+    }
+
+    #[test]
+    fn it_reoptimizes_after_toggles() {
+        let input = "cpy 2 a
+        tgl a
+        tgl a
+        tgl a
+        cpy 1 a
+        dec a
+        dec a";
+        assert_eq!(run(input, Registers::default(), false).0, run(input, Registers::default(), true).0);
+    }
+}
@@ -0,0 +1,76 @@
+extern crate advent2016;
+
+use advent2016::assembunny::listing::Listing;
+use advent2016::assembunny::{Machine, Registers};
+use std::env;
+use std::io;
+use std::io::Read;
+use std::process;
+use std::str::FromStr;
+
+const USAGE: &str = "usage: assembunny [-a <n>] [-b <n>] [-c <n>] [-d <n>] [--max-steps <n>] [--no-optimize] < program
+       assembunny --list [--optimize] < program";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = run(&args) {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut registers = Registers::default();
+    let mut max_steps = None;
+    let mut optimized = true;
+    let mut list = false;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--no-optimize" {
+            optimized = false;
+            continue;
+        }
+        if arg == "--list" {
+            list = true;
+            optimized = false;
+            continue;
+        }
+        if arg == "--optimize" {
+            optimized = true;
+            continue;
+        }
+        let value = match iter.next() {
+            Some(value) => value,
+            None => return Err(format!("{} needs a value\n{}", arg, USAGE)),
+        };
+        match arg.as_str() {
+            "-a" => registers.a = parse_number(arg, value)?,
+            "-b" => registers.b = parse_number(arg, value)?,
+            "-c" => registers.c = parse_number(arg, value)?,
+            "-d" => registers.d = parse_number(arg, value)?,
+            "--max-steps" => max_steps = Some(parse_number(arg, value)?),
+            _ => return Err(format!("unknown argument {}\n{}", arg, USAGE)),
+        }
+    }
+
+    let mut program = String::new();
+    io::stdin().read_to_string(&mut program).map_err(|e| e.to_string())?;
+    let mut machine = Machine::with_registers(registers);
+    machine.set_optimized(optimized);
+    machine.load(&program).map_err(|e| e.to_string())?;
+    if list {
+        print!("{}", Listing::new(machine.program()));
+        return Ok(());
+    }
+
+    let registers = machine.execute(max_steps, |value| {
+        println!("out {}", value);
+        true
+    }).map_err(|e| e.to_string())?;
+    println!("{}", registers);
+    Ok(())
+}
+
+fn parse_number<T: FromStr>(name: &str, raw: &str) -> Result<T, String> {
+    raw.parse().map_err(|_| format!("{} must be a number, got {}", name, raw))
+}
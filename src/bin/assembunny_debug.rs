@@ -0,0 +1,149 @@
+extern crate advent2016;
+
+use advent2016::assembunny::debugger::{Debugger, Stop};
+use advent2016::assembunny::{Machine, Register};
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::{BufRead, Read, Write};
+use std::process;
+
+const HELP: &str = "commands:
+  s [<n>]        step one or <n> instructions
+  c              continue until a breakpoint, watchpoint or halt
+  b <ip>         set a breakpoint          d <ip>   delete a breakpoint
+  w <register>   watch a register          u <reg>  stop watching a register
+  set <reg> <n>  change a register
+  t              toggle tracing
+  r              show ip and registers
+  counts         show how often each instruction ran
+  q              quit";
+
+fn main() {
+    let path = match env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("usage: assembunny_debug <program>");
+            process::exit(1);
+        },
+    };
+    let mut program = String::new();
+    if let Err(e) = File::open(&path).and_then(|mut file| file.read_to_string(&mut program)) {
+        eprintln!("error: couldn't read {}: {}", path, e);
+        process::exit(1);
+    }
+
+    let mut machine = Machine::new();
+    if let Err(e) = machine.load(&program) {
+        eprintln!("error: {}: {}", path, e);
+        process::exit(1);
+    }
+
+    let mut debugger = Debugger::new(machine);
+    println!("{}", HELP);
+    prompt();
+    let stdin = io::stdin();
+    for line in stdin.lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(_) => break,
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.first() == Some(&"q") {
+            break;
+        }
+        if let Err(message) = command(&mut debugger, &words) {
+            println!("{}", message);
+        }
+        prompt();
+    }
+}
+
+fn prompt() {
+    print!("> ");
+    let _ = io::stdout().flush();
+}
+
+fn command(debugger: &mut Debugger, words: &[&str]) -> Result<(), String> {
+    match words {
+        [] => Ok(()),
+        ["s"] => {
+            let stop = debugger.step();
+            report(debugger, Stop::Stepped, stop)
+        },
+        ["s", count] => {
+            let mut stop = Stop::Stepped;
+            for _ in 0..number(count)? {
+                stop = debugger.step();
+                if stop != Stop::Stepped {
+                    break;
+                }
+            }
+            report(debugger, Stop::Stepped, stop)
+        },
+        ["c"] => {
+            let stop = debugger.resume();
+            report(debugger, Stop::Halted, stop)
+        },
+        ["b", ip] => {
+            debugger.add_breakpoint(number(ip)?);
+            Ok(())
+        },
+        ["d", ip] => {
+            debugger.remove_breakpoint(number(ip)?);
+            Ok(())
+        },
+        ["w", register] => {
+            debugger.watch(register_name(register)?);
+            Ok(())
+        },
+        ["u", register] => {
+            debugger.unwatch(register_name(register)?);
+            Ok(())
+        },
+        ["set", register, value] => {
+            let register = register_name(register)?;
+            let value = value.parse().map_err(|_| format!("not a number: {}", value))?;
+            debugger.set_register(&register, value);
+            Ok(())
+        },
+        ["t"] => {
+            let tracing = !debugger.is_tracing();
+            debugger.set_tracing(tracing);
+            println!("tracing {}", if tracing { "on" } else { "off" });
+            Ok(())
+        },
+        ["r"] => report(debugger, Stop::Stepped, Stop::Stepped),
+        ["counts"] => {
+            let machine = debugger.machine();
+            for (ip, (count, instruction)) in debugger.counts().iter().zip(machine.instructions()).enumerate() {
+                println!("{:>4}  {:>12}  {}", ip, count, instruction);
+            }
+            Ok(())
+        },
+        _ => Err(format!("unknown command, try:\n{}", HELP)),
+    }
+}
+
+fn report(debugger: &mut Debugger, quiet: Stop, stop: Stop) -> Result<(), String> {
+    for entry in debugger.take_trace() {
+        println!("{}", entry);
+    }
+    if stop != quiet {
+        println!("{}", stop);
+    }
+    let machine = debugger.machine();
+    match machine.current_instruction() {
+        Some(instruction) => println!("ip={} {}  {}", machine.ip(), instruction, machine.registers),
+        None => println!("ip={} halted  {}", machine.ip(), machine.registers),
+    }
+    Ok(())
+}
+
+fn number(raw: &str) -> Result<usize, String> {
+    raw.parse().map_err(|_| format!("not a number: {}", raw))
+}
+
+fn register_name(raw: &str) -> Result<Register, String> {
+    raw.parse().map_err(|_| format!("not a register: {}", raw))
+}
@@ -0,0 +1,35 @@
+extern crate advent2016;
+
+use advent2016::day1::moves::Moves;
+use advent2016::day1::route::Route;
+use std::env;
+use std::io;
+use std::process;
+
+const USAGE: &str = "usage: day1_route [--svg] < instructions";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = run(&args) {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let svg = match args.first().map(|arg| arg.as_str()) {
+        None => false,
+        Some("--svg") if args.len() == 1 => true,
+        _ => return Err(USAGE.to_string()),
+    };
+
+    let stdin = io::stdin();
+    let moves = Moves::new(stdin.lock()).collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())?;
+    let route = Route::new(&moves);
+    if svg {
+        print!("{}", route.svg());
+    } else {
+        println!("{}", route.ascii());
+    }
+    Ok(())
+}
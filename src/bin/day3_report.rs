@@ -0,0 +1,41 @@
+extern crate advent2016;
+
+use advent2016::day3::report::Report;
+use advent2016::day3::triangle::{Layout, Triangles};
+use std::env;
+use std::io;
+use std::process;
+
+const USAGE: &str = "usage: day3_report [--columns] [--csv] < triangles";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = run(&args) {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut layout = Layout::Rows;
+    let mut csv = false;
+    for arg in args {
+        match arg.as_str() {
+            "--columns" => layout = Layout::Columns,
+            "--csv" => csv = true,
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+
+    let stdin = io::stdin();
+    let mut report = Report::default();
+    for triangle in Triangles::new(stdin.lock(), layout) {
+        report.push(triangle.map_err(|e| e.to_string())?);
+    }
+    if csv {
+        print!("{}", report.csv());
+    } else {
+        print!("{}", report);
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+extern crate advent2016;
+
+use advent2016::day4::query::{self, Matcher};
+use advent2016::day4::RoomCode;
+use advent2016::parse::parse_lines;
+use std::env;
+use std::io;
+use std::io::Read;
+use std::process;
+
+const USAGE: &str = "usage: day4_rooms [--exact NAME | --contains TEXT | --regex PATTERN] [--json] < rooms";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = run(&args) {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut matcher = None;
+    let mut json = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let next = match arg.as_str() {
+            "--json" => {
+                json = true;
+                continue;
+            },
+            "--exact" => Matcher::exact(value(args.next())?),
+            "--contains" => Matcher::substring(value(args.next())?),
+            "--regex" => Matcher::pattern(value(args.next())?).map_err(|e| e.to_string())?,
+            _ => return Err(USAGE.to_string()),
+        };
+        if matcher.replace(next).is_some() {
+            return Err(USAGE.to_string());
+        }
+    }
+    // With no query every valid room is listed.
+    let matcher = matcher.unwrap_or_else(|| Matcher::substring(""));
+
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).map_err(|e| e.to_string())?;
+    let codes: Vec<RoomCode> = parse_lines(&input).map_err(|e| e.to_string())?;
+    let rooms = query::search(&codes, &matcher);
+    if json {
+        print!("{}", query::json(&rooms));
+    } else {
+        for (sector_id, name) in rooms {
+            println!("{} {}", sector_id, name);
+        }
+    }
+    Ok(())
+}
+
+fn value(arg: Option<&String>) -> Result<&str, String> {
+    arg.map(|arg| arg.as_str()).ok_or_else(|| USAGE.to_string())
+}
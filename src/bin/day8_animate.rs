@@ -0,0 +1,56 @@
+extern crate advent2016;
+
+use advent2016::day8::recorder::{Format, Recorder};
+use advent2016::day8::{Display, HEIGHT, WIDTH};
+use std::env;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+const USAGE: &str = "usage: day8_animate [--width <n>] [--height <n>] [--delay <ms>] < instructions
+       day8_animate [--width <n>] [--height <n>] (--pbm | --pgm) <directory> < instructions";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(message) = run(&args) {
+        eprintln!("error: {}", message);
+        process::exit(1);
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let mut width = WIDTH;
+    let mut height = HEIGHT;
+    let mut delay = 50;
+    let mut images = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = match iter.next() {
+            Some(value) => value,
+            None => return Err(format!("{} needs a value\n{}", arg, USAGE)),
+        };
+        match arg.as_str() {
+            "--width" => width = parse_number(arg, value)?,
+            "--height" => height = parse_number(arg, value)?,
+            "--delay" => delay = parse_number(arg, value)?,
+            "--pbm" => images = Some((Format::Pbm, value)),
+            "--pgm" => images = Some((Format::Pgm, value)),
+            _ => return Err(format!("unknown argument {}\n{}", arg, USAGE)),
+        }
+    }
+
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input).map_err(|e| e.to_string())?;
+    let recorder = Recorder::record(Display::with_size(width, height), &input).map_err(|e| e.to_string())?;
+    match images {
+        Some((format, directory)) => recorder.write_images(Path::new(directory), format),
+        None => recorder.play(&mut io::stdout(), Duration::from_millis(delay)),
+    }.map_err(|e| e.to_string())
+}
+
+fn parse_number<T: FromStr>(name: &str, raw: &str) -> Result<T, String> {
+    raw.parse().map_err(|_| format!("{} must be a number, got {}", name, raw))
+}
@@ -0,0 +1,23 @@
+extern crate advent2016;
+
+use advent2016::day8::inverse;
+use advent2016::day8::Display;
+use std::io;
+use std::io::Read;
+use std::process;
+
+fn main() {
+    let mut art = String::new();
+    io::stdin().read_to_string(&mut art).expect("failed to read stdin");
+    match Display::from_art(&art) {
+        Ok(target) => {
+            for instruction in inverse::instructions(&target) {
+                println!("{}", instruction);
+            }
+        },
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        },
+    }
+}
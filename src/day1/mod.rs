@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+use geometry::{Point, ORIGIN};
+use parse::ParseError;
+use solution::{Answer, Solution, SolveError};
+
+pub use geometry::Direction;
+
+pub mod moves;
+pub mod route;
+
+use self::moves::{parse_moves, Move};
+
+#[test]
+fn test_turn_left() {
+    assert_eq!(Direction::West, Direction::North.turn_left());
+    assert_eq!(Direction::North, Direction::East.turn_left());
+    assert_eq!(Direction::East, Direction::South.turn_left());
+    assert_eq!(Direction::South, Direction::West.turn_left());
+}
+
+#[test]
+fn test_turn_right() {
+    assert_eq!(Direction::East, Direction::North.turn_right());
+    assert_eq!(Direction::South, Direction::East.turn_right());
+    assert_eq!(Direction::West, Direction::South.turn_right());
+    assert_eq!(Direction::North, Direction::West.turn_right());
+}
+
+// The corners of the walk, starting at the origin and ending where it stops.
+pub fn trace(moves: &[Move]) -> Vec<Point> {
+    let mut position = ORIGIN;
+    let mut current_direction = Direction::North;
+    let mut path = vec![position];
+    for step in moves {
+        current_direction = step.direction(current_direction);
+        position = position.moved(current_direction, step.distance);
+        path.push(position);
+    }
+    path
+}
+
+pub fn follow_instructions(moves: &[Move]) -> i32 {
+    trace(moves).last().unwrap().manhattan(&ORIGIN)
+}
+
+#[test]
+fn traces_corners() {
+    let moves = parse_moves("R2, L3, L1").unwrap();
+    let expected = vec![ORIGIN, Point::new(2, 0), Point::new(2, 3), Point::new(1, 3)];
+    assert_eq!(expected, trace(&moves));
+}
+
+#[test]
+fn follow_instructions_one_move_left() {
+    let left = parse_moves("L1").unwrap();
+    assert_eq!(1, follow_instructions(&left));
+}
+
+#[test]
+fn follow_instructions_two_moves_left() {
+    let left = parse_moves("L2").unwrap();
+    assert_eq!(2, follow_instructions(&left));
+}
+
+#[test]
+fn follow_instructions_one_move_right() {
+    let right = parse_moves("R1").unwrap();
+    assert_eq!(1, follow_instructions(&right));
+}
+
+#[test]
+fn follow_instructions_two_moves_right() {
+    let right = parse_moves("R2").unwrap();
+    assert_eq!(2, follow_instructions(&right));
+}
+
+#[test]
+fn follow_instructions_right_then_left() {
+    let moves = parse_moves("R2, L3").unwrap();
+    assert_eq!(5, follow_instructions(&moves));
+}
+
+#[test]
+fn follow_instructions_all_rights() {
+    let moves = parse_moves("R2, R2, R2").unwrap();
+    assert_eq!(2, follow_instructions(&moves));
+}
+
+#[test]
+fn follow_instructions_complex() {
+    let moves = parse_moves("R5, L5, R5, R3").unwrap();
+    assert_eq!(12, follow_instructions(&moves));
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Revisit {
+    pub location: Point,
+    pub step: usize,
+}
+
+// Every block walked through that had been visited before, in order, with
+// the number of blocks walked to get there.
+pub fn revisits(moves: &[Move]) -> Vec<Revisit> {
+    let mut position = ORIGIN;
+    let mut current_direction = Direction::North;
+    let mut visited = HashSet::new();
+    visited.insert(position);
+    let mut revisits = vec![];
+    let mut step = 0;
+    for next in moves {
+        current_direction = next.direction(current_direction);
+        for _ in 0..next.distance {
+            position = position.step(current_direction);
+            step += 1;
+            if !visited.insert(position) {
+                revisits.push(Revisit { location: position, step });
+            }
+        }
+    }
+    revisits
+}
+
+pub fn dist_to_first_revisit(moves: &[Move]) -> Option<i32> {
+    revisits(moves).first().map(|revisit| revisit.location.manhattan(&ORIGIN))
+}
+
+#[test]
+fn no_revisits() {
+    let moves = parse_moves("R5, L5, R5, R3").unwrap();
+    assert!(dist_to_first_revisit(&moves).is_none())
+}
+
+#[test]
+fn found_revisit() {
+    let moves = parse_moves("R8, R4, R4, R8").unwrap();
+    assert_eq!(4, dist_to_first_revisit(&moves).unwrap());
+}
+
+#[test]
+fn reports_every_revisit() {
+    let moves = parse_moves("R8, R4, R4, R8, R2, R6").unwrap();
+    let expected = vec![
+        Revisit { location: Point::new(4, 0), step: 20 },
+        Revisit { location: Point::new(6, 0), step: 30 },
+    ];
+    assert_eq!(expected, revisits(&moves));
+}
+
+#[test]
+fn walks_long_paths_quickly() {
+    let moves = parse_moves("R1000, R1000, R1000, R1000").unwrap();
+    let revisits = revisits(&moves);
+    assert_eq!(1, revisits.len());
+    assert_eq!(Revisit { location: ORIGIN, step: 4000 }, revisits[0]);
+    assert_eq!(Some(0), dist_to_first_revisit(&moves));
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(follow_instructions(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        dist_to_first_revisit(input)
+            .map(|dist| dist.to_string())
+            .ok_or(SolveError::NoAnswer)
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::io::{BufReader, Bytes, Read};
+use std::str::FromStr;
+
+use geometry::Direction;
+use parse::{ParseError, ReadError};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Move {
+    pub turn: Turn,
+    pub distance: i32,
+}
+
+impl Move {
+    pub fn new(turn: Turn, distance: i32) -> Move {
+        Move { turn, distance }
+    }
+
+    pub fn direction(&self, facing: Direction) -> Direction {
+        match self.turn {
+            Turn::Left => facing.turn_left(),
+            Turn::Right => facing.turn_right(),
+        }
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let turn = match self.turn {
+            Turn::Left => 'L',
+            Turn::Right => 'R',
+        };
+        write!(f, "{}{}", turn, self.distance)
+    }
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let turn = match s.chars().next() {
+            Some('L') => Turn::Left,
+            Some('R') => Turn::Right,
+            _ => return Err(ParseError::new(1, s, "L or R and a distance")),
+        };
+        let distance = &s[1..];
+        match distance.parse() {
+            Ok(distance) if distance >= 0 => Ok(Move::new(turn, distance)),
+            _ => Err(ParseError::new(2, distance, "a distance")),
+        }
+    }
+}
+
+// Splits input fed a byte at a time into moves. Commas and whitespace
+// separate moves in any mix, and errors give the line and column the bad
+// move starts at.
+pub struct Tokenizer {
+    token: Vec<u8>,
+    line: usize,
+    column: usize,
+    start: (usize, usize),
+}
+
+impl Default for Tokenizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Tokenizer {
+    pub fn new() -> Tokenizer {
+        Tokenizer {
+            token: vec![],
+            line: 1,
+            column: 0,
+            start: (1, 1),
+        }
+    }
+
+    pub fn feed(&mut self, byte: u8) -> Option<Result<Move, ParseError>> {
+        self.column += 1;
+        let separator = byte == b',' || (byte as char).is_ascii_whitespace();
+        let result = if separator { self.finish() } else { None };
+        if byte == b'\n' {
+            self.line += 1;
+            self.column = 0;
+        }
+        if !separator {
+            if self.token.is_empty() {
+                self.start = (self.line, self.column);
+            }
+            self.token.push(byte);
+        }
+        result
+    }
+
+    // Ends the move in progress, if there is one.
+    pub fn finish(&mut self) -> Option<Result<Move, ParseError>> {
+        if self.token.is_empty() {
+            return None;
+        }
+        let (line, column) = self.start;
+        let token = String::from_utf8_lossy(&self.token).into_owned();
+        self.token.clear();
+        Some(token.parse().map_err(|e: ParseError| e.shifted(column - 1).on_line(line)))
+    }
+}
+
+pub fn parse_moves(input: &str) -> Result<Vec<Move>, ParseError> {
+    let mut tokenizer = Tokenizer::new();
+    let mut moves = vec![];
+    for byte in input.bytes() {
+        if let Some(parsed) = tokenizer.feed(byte) {
+            moves.push(parsed?);
+        }
+    }
+    if let Some(parsed) = tokenizer.finish() {
+        moves.push(parsed?);
+    }
+    Ok(moves)
+}
+
+// Reads moves one at a time, without holding the whole input in memory.
+pub struct Moves<R: Read> {
+    bytes: Bytes<BufReader<R>>,
+    tokenizer: Tokenizer,
+    done: bool,
+}
+
+impl<R: Read> Moves<R> {
+    pub fn new(reader: R) -> Moves<R> {
+        Moves {
+            bytes: BufReader::new(reader).bytes(),
+            tokenizer: Tokenizer::new(),
+            done: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for Moves<R> {
+    type Item = Result<Move, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let parsed = match self.bytes.next() {
+                Some(Ok(byte)) => self.tokenizer.feed(byte),
+                Some(Err(error)) => {
+                    self.done = true;
+                    return Some(Err(ReadError::Io(error)));
+                },
+                None => {
+                    self.done = true;
+                    self.tokenizer.finish()
+                },
+            };
+            if let Some(parsed) = parsed {
+                if parsed.is_err() {
+                    self.done = true;
+                }
+                return Some(parsed.map_err(ReadError::Parse));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_moves() {
+        assert_eq!(Ok(Move::new(Turn::Left, 12)), "L12".parse());
+        assert_eq!("R3", Move::new(Turn::Right, 3).to_string());
+        assert_eq!(ParseError::new(1, "X2", "L or R and a distance"), "X2".parse::<Move>().unwrap_err());
+        assert_eq!(ParseError::new(2, "", "a distance"), "R".parse::<Move>().unwrap_err());
+    }
+
+    #[test]
+    fn it_accepts_any_mix_of_separators() {
+        let expected = vec![Move::new(Turn::Right, 2), Move::new(Turn::Left, 3), Move::new(Turn::Right, 1), Move::new(Turn::Left, 10)];
+        assert_eq!(Ok(expected.clone()), parse_moves("R2, L3,R1\n\tL10\n"));
+        assert_eq!(Ok(expected), parse_moves(",R2 ,, L3\r\nR1,L10"));
+        assert_eq!(Ok(vec![]), parse_moves(" \n"));
+    }
+
+    #[test]
+    fn it_reports_positions() {
+        let error = parse_moves("R2, L3,\n  R1, Lx").unwrap_err();
+        assert_eq!("line 2, column 8: expected a distance, found `x`", error.to_string());
+        let error = parse_moves("R2,Q5").unwrap_err();
+        assert_eq!((1, 4), (error.line, error.column));
+    }
+
+    #[test]
+    fn it_streams_from_readers() {
+        let moves = Moves::new("R5, L5,\nR5, R3".as_bytes()).map(|m| m.unwrap()).collect::<Vec<_>>();
+        assert_eq!(4, moves.len());
+        assert_eq!(Move::new(Turn::Right, 3), moves[3]);
+    }
+
+    #[test]
+    fn it_stops_streaming_after_an_error() {
+        let mut moves = Moves::new("R1 L? R2".as_bytes());
+        assert_eq!(Move::new(Turn::Right, 1), moves.next().unwrap().unwrap());
+        match moves.next() {
+            Some(Err(ReadError::Parse(error))) => assert_eq!((1, 5), (error.line, error.column)),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(moves.next().is_none());
+    }
+}
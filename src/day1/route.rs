@@ -0,0 +1,152 @@
+use std::fmt::Write;
+
+use day1::moves::Move;
+use day1::{revisits, trace};
+use geometry::Point;
+
+pub struct Route {
+    pub path: Vec<Point>,
+    pub first_revisit: Option<Point>,
+}
+
+impl Route {
+    pub fn new(moves: &[Move]) -> Route {
+        Route {
+            path: trace(moves),
+            first_revisit: revisits(moves).first().map(|revisit| revisit.location),
+        }
+    }
+
+    pub fn start(&self) -> Point {
+        self.path[0]
+    }
+
+    pub fn end(&self) -> Point {
+        self.path[self.path.len() - 1]
+    }
+
+    // Bottom left and top right corners of the box around the route.
+    pub fn bounds(&self) -> (Point, Point) {
+        let xs = self.path.iter().map(|p| p.x);
+        let ys = self.path.iter().map(|p| p.y);
+        let min = Point::new(xs.clone().min().unwrap(), ys.clone().min().unwrap());
+        let max = Point::new(xs.max().unwrap(), ys.max().unwrap());
+        (min, max)
+    }
+
+    // North is up. Corners and crossings are `+`, the start `S`, the end `E`
+    // and the first block visited twice `X`.
+    pub fn ascii(&self) -> String {
+        let (min, max) = self.bounds();
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        let mut grid = vec![vec![' '; width]; height];
+        {
+            let mut plot = |point: Point, c: char| {
+                let cell = &mut grid[(max.y - point.y) as usize][(point.x - min.x) as usize];
+                *cell = match (*cell, c) {
+                    (' ', c) => c,
+                    (old, new) if old == new => new,
+                    _ => '+',
+                };
+            };
+            for segment in self.path.windows(2) {
+                let (from, to) = (segment[0], segment[1]);
+                let line = if from.y == to.y { '-' } else { '|' };
+                let length = from.manhattan(&to);
+                let step = Point::new((to.x - from.x).signum(), (to.y - from.y).signum());
+                for i in 1..length {
+                    plot(from + step * i, line);
+                }
+                plot(from, '+');
+                plot(to, '+');
+            }
+        }
+
+        let mut marks = vec![(self.start(), 'S'), (self.end(), 'E')];
+        if let Some(revisit) = self.first_revisit {
+            marks.push((revisit, 'X'));
+        }
+        for (point, mark) in marks {
+            grid[(max.y - point.y) as usize][(point.x - min.x) as usize] = mark;
+        }
+
+        grid.iter()
+            .map(|row| row.iter().collect::<String>().trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    // SVG has y growing downwards, so the route is drawn with y negated to
+    // keep north at the top.
+    pub fn svg(&self) -> String {
+        let (min, max) = self.bounds();
+        let span = (max.x - min.x).max(max.y - min.y);
+        let radius = (f64::from(span) / 80.0).max(0.4);
+        let margin = radius.ceil() as i32;
+        let points = self.path.iter()
+            .map(|p| format!("{},{}", p.x, -p.y))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut svg = String::new();
+        writeln!(svg, r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+                 min.x - margin, -max.y - margin, max.x - min.x + 2 * margin, max.y - min.y + 2 * margin).unwrap();
+        writeln!(svg, r#"  <polyline points="{}" fill="none" stroke="black" stroke-width="1" vector-effect="non-scaling-stroke"/>"#, points).unwrap();
+        let mut marks = vec![(self.start(), "start", "green"), (self.end(), "end", "red")];
+        if let Some(revisit) = self.first_revisit {
+            marks.push((revisit, "first revisit", "blue"));
+        }
+        for (point, title, colour) in marks {
+            writeln!(svg, r#"  <circle cx="{}" cy="{}" r="{}" fill="{}"><title>{}</title></circle>"#,
+                     point.x, -point.y, radius, colour, title).unwrap();
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day1::moves::parse_moves;
+
+    #[test]
+    fn it_bounds_the_route() {
+        let route = Route::new(&parse_moves("R8, R4, R4, R8").unwrap());
+        assert_eq!((Point::new(0, -4), Point::new(8, 4)), route.bounds());
+        assert_eq!(Some(Point::new(4, 0)), route.first_revisit);
+    }
+
+    #[test]
+    fn it_draws_ascii() {
+        let route = Route::new(&parse_moves("R8, R4, R4, R8").unwrap());
+        let expected = "    E
+    |
+    |
+    |
+S---X---+
+    |   |
+    |   |
+    |   |
+    +---+";
+        assert_eq!(expected, route.ascii());
+    }
+
+    #[test]
+    fn it_draws_ascii_without_revisits() {
+        let route = Route::new(&parse_moves("R2, L3").unwrap());
+        assert_eq!("  E\n  |\n  |\nS-+", route.ascii());
+    }
+
+    #[test]
+    fn it_exports_svg() {
+        let svg = Route::new(&parse_moves("R2, L3").unwrap()).svg();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="-1 -4 4 5">"#));
+        assert!(svg.contains(r#"points="0,0 2,0 2,-3""#));
+        assert!(svg.contains(r#"<circle cx="0" cy="0" r="0.4" fill="green"><title>start</title></circle>"#));
+        assert!(svg.contains(r#"<circle cx="2" cy="-3" r="0.4" fill="red"><title>end</title></circle>"#));
+        assert!(!svg.contains("first revisit"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use parse::ParseError;
+use solution::{Answer, Solution, SolveError};
+
+use self::part1::Spot;
+
+pub mod part1;
+pub mod part2;
+
+// On top of the syntax the parts check, every bot given a chip has to be
+// defined, only once, and can't hand chips to itself or round a loop.
+pub fn validate(input: &str) -> Result<(), ParseError> {
+    let mut bots: Vec<Vec<Spot>> = vec![];
+    let mut defined = HashMap::new();
+    let mut references = vec![];
+    for (index, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let indent = raw_line.len() - raw_line.trim_start().len();
+        let place = |e: ParseError| e.shifted(indent).on_line(index + 1);
+        if line.starts_with("bot") {
+            let bot: part1::Bot = line.parse().map_err(place)?;
+            let spots = bot.spots().iter().map(|spot| spot.placed(index + 1, indent)).collect::<Vec<_>>();
+            if defined.insert(spots[0].bot, bots.len()).is_some() {
+                return Err(spots[0].error("a bot not already defined"));
+            }
+            if let Some(spot) = spots[1..].iter().find(|spot| spot.bot == spots[0].bot) {
+                return Err(spot.error("a bot other than itself"));
+            }
+            references.extend(spots[1..].iter().cloned());
+            bots.push(spots);
+        } else {
+            let (spot, _) = part1::parse_value_spot(line).map_err(place)?;
+            references.push(spot.placed(index + 1, indent));
+        }
+    }
+    if let Some(spot) = references.iter().find(|spot| !defined.contains_key(&spot.bot)) {
+        return Err(spot.error("a defined bot"));
+    }
+    let mut done = HashMap::new();
+    for spots in &bots {
+        check_loops(spots[0].bot, &bots, &defined, &mut done)?;
+    }
+    Ok(())
+}
+
+// A chip handed round a loop would come back to a bot still busy passing
+// chips on. `done` is false for the bots on the current path and true for
+// bots already known to lead nowhere back.
+fn check_loops(bot: usize, bots: &[Vec<Spot>], defined: &HashMap<usize, usize>, done: &mut HashMap<usize, bool>) -> Result<(), ParseError> {
+    if done.contains_key(&bot) {
+        return Ok(());
+    }
+    done.insert(bot, false);
+    for target in &bots[defined[&bot]][1..] {
+        match done.get(&target.bot) {
+            Some(&false) => return Err(target.error(&format!("a bot that doesn't pass chips back to bot {}", bot))),
+            Some(&true) => (),
+            None => check_loops(target.bot, bots, defined, done)?,
+        }
+    }
+    done.insert(bot, true);
+    Ok(())
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        validate(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut bots = HashMap::new();
+        let mut instructions = vec![];
+        part1::parse(input, &mut bots, &mut instructions)?;
+        let _ = part1::work(&mut bots, instructions);
+        part1::find_comparer(&bots, 17, 61)
+            .map(|number| number.to_string())
+            .ok_or(SolveError::NoAnswer)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut bots = HashMap::new();
+        let mut instructions = vec![];
+        part2::parse(input, &mut bots, &mut instructions)?;
+        let outputs = part2::work(&mut bots, instructions);
+        match (outputs.get(&0), outputs.get(&1), outputs.get(&2)) {
+            (Some(a), Some(b), Some(c)) => Ok((a * b * c).to_string()),
+            _ => Err(SolveError::NoAnswer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "value 5 goes to bot 2
+        bot 2 gives low to bot 1 and high to bot 0
+        value 3 goes to bot 1
+        bot 1 gives low to output 1 and high to bot 0
+        bot 0 gives low to output 2 and high to output 0
+        value 2 goes to bot 2";
+
+    #[test]
+    fn it_accepts_complete_instructions() {
+        assert!(Day10::parse(INPUT).is_ok());
+    }
+
+    #[test]
+    fn it_reports_malformed_instructions() {
+        let error = Day10::parse("value 5 goes to bot 2\n  bot 2 gives low to bot x and high to bot 0").unwrap_err();
+        assert_eq!("line 2, column 26: expected a bot number, found `x`", error.to_string());
+        let error = Day10::parse(&INPUT.replace("value 2 goes to bot 2", "value 2 goes to bot 7")).unwrap_err();
+        assert_eq!(ParseError::new(29, "7", "a defined bot").on_line(6), error);
+        let error = Day10::parse("bot 2 gives low to output 1 and high to bot 2").unwrap_err();
+        assert_eq!(ParseError::new(45, "2", "a bot other than itself"), error);
+        let error = Day10::parse(&format!("{}\nbot 1 gives low to output 1 and high to output 0", INPUT)).unwrap_err();
+        assert_eq!(ParseError::new(5, "1", "a bot not already defined").on_line(7), error);
+    }
+
+    #[test]
+    fn it_rejects_bots_handing_chips_round_a_loop() {
+        let input = "value 1 goes to bot 1
+                     value 2 goes to bot 1
+                     bot 1 gives low to bot 2 and high to output 0
+                     bot 2 gives low to output 1 and high to bot 3
+                     bot 3 gives low to bot 1 and high to output 2";
+        let error = Day10::parse(input).unwrap_err();
+        assert_eq!(ParseError::new(45, "1", "a bot that doesn't pass chips back to bot 3").on_line(5), error);
+        assert!(Day10::parse(&input.replace("to bot 1 and high to output 2", "to output 3 and high to output 2")).is_ok());
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::HashMap;
+use std::cmp;
+use std::str::FromStr;
+use parse::{ParseError, Tokens};
+
+pub struct Bot {
+    number: usize,
+    low: Give,
+    high: Give,
+    chips: Option<usize>,
+}
+
+impl Bot {
+    pub fn take(&mut self, chip: usize, bots: &mut HashMap<usize, Bot>, outputs: &mut HashMap<usize, usize>) {
+        if self.chips.is_none() {
+            self.chips = Some(chip);
+        } else {
+            let min = cmp::min(self.chips.unwrap(), chip);
+            let max = cmp::max(self.chips.unwrap(), chip);
+
+            match self.low {
+                Give::Bot(i) => {
+                    let mut low_bot = bots.remove(&i).unwrap();
+                    low_bot.take(min, bots, outputs);
+                    bots.insert(low_bot.number, low_bot);
+                },
+                Give::Output(i) => { outputs.insert(i, min); },
+            }
+
+
+            match self.high {
+                Give::Bot(i) => {
+                    let mut high_bot = bots.remove(&i).unwrap();
+                    high_bot.take(max, bots, outputs);
+                    bots.insert(high_bot.number, high_bot);
+                },
+                Give::Output(i) => { outputs.insert(i, max); },
+            }
+            self.chips = None;
+        }
+    }
+}
+
+impl FromStr for Bot {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = Tokens::new(s);
+        tokens.next_token("`bot`")?.expect("bot")?;
+        let number = tokens.next_token("a bot number")?.parse("a bot number")?;
+        tokens.skip(3)?;
+        let low = parse_give(&mut tokens)?;
+        tokens.skip(3)?;
+        let high = parse_give(&mut tokens)?;
+        tokens.finish()?;
+        Ok(Bot{
+            number,
+            low,
+            high,
+            chips: None,
+        })
+    }
+}
+
+#[derive(PartialEq)]
+#[derive(Debug)]
+pub enum Give {
+    Bot(usize),
+    Output(usize),
+}
+
+pub fn parse_give(tokens: &mut Tokens) -> Result<Give, ParseError> {
+    let kind = tokens.next_token("`bot` or `output`")?;
+    match kind.text {
+        "bot" => Ok(Give::Bot(tokens.next_token("a bot number")?.parse("a bot number")?)),
+        "output" => Ok(Give::Output(tokens.next_token("an output number")?.parse("an output number")?)),
+        _ => Err(kind.error("`bot` or `output`")),
+    }
+}
+
+pub fn parse_value(s: &str) -> Result<(usize, usize), ParseError> {
+    let mut tokens = Tokens::new(s);
+    tokens.next_token("`value` or `bot`")?.expect("value")?;
+    let value = tokens.next_token("a chip value")?.parse("a chip value")?;
+    tokens.skip(3)?;
+    let bot = tokens.next_token("a bot number")?.parse("a bot number")?;
+    tokens.finish()?;
+    Ok((bot, value))
+}
+
+pub fn parse(input: &str, bots: &mut HashMap<usize, Bot>, instructions: &mut Vec<(usize, usize)>) -> Result<(), ParseError> {
+    for (index, raw_line) in input.lines().enumerate() {
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let indent = raw_line.len() - raw_line.trim_start().len();
+        let parsed = if line.starts_with("bot") {
+            line.parse().map(|bot: Bot| { bots.insert(bot.number, bot); })
+        } else {
+            parse_value(line).map(|instruction| instructions.push(instruction))
+        };
+        parsed.map_err(|e| e.shifted(indent).on_line(index + 1))?;
+    }
+    Ok(())
+}
+
+pub fn work(bots: &mut HashMap<usize, Bot>, instructions: Vec<(usize, usize)>) -> HashMap<usize, usize> {
+    let mut outputs = HashMap::new();
+    for (bot_number, chip) in instructions {
+        let mut bot = bots.remove(&bot_number).unwrap();
+        bot.take(chip, bots, &mut outputs);
+        bots.insert(bot.number, bot);
+    }
+
+    outputs
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use super::*;
+
+    #[test]
+    fn it_can_make_a_bot_with_a_number() {
+        let bot: Bot = "bot 2 gives low to bot 1 and high to bot 0".parse().unwrap();
+        assert_eq!(2, bot.number);
+    }
+
+    #[test]
+    fn it_can_make_a_bot_with_a_low_to_bot() {
+        let bot: Bot = "bot 2 gives low to bot 1 and high to bot 0".parse().unwrap();
+        assert_eq!(Give::Bot(1), bot.low);
+    }
+
+    #[test]
+    fn it_can_make_a_bot_with_a_hight_to_bot() {
+        let bot: Bot = "bot 2 gives low to bot 1 and high to bot 0".parse().unwrap();
+        assert_eq!(Give::Bot(0), bot.high);
+    }
+
+    #[test]
+    fn it_can_make_a_bot_with_low_to_output() {
+        let bot: Bot = "bot 0 gives low to output 2 and high to output 0".parse().unwrap();
+        assert_eq!(Give::Output(2), bot.low);
+    }
+
+    #[test]
+    fn it_can_make_a_bot_with_high_to_output() {
+        let bot: Bot = "bot 0 gives low to output 2 and high to output 0".parse().unwrap();
+        assert_eq!(Give::Output(0), bot.high);
+    }
+
+    #[test]
+    fn it_takes_one_chip() {
+        let mut outputs = HashMap::new();
+        let mut bot: Bot = "bot 2 gives low to bot 1 and high to bot 0".parse().unwrap();
+        bot.take(4, &mut get_bots(), &mut outputs);
+        assert_eq!(Some(4), bot.chips);
+    }
+
+    fn get_bots() -> HashMap<usize, Bot> {
+        let mut bots = HashMap::new();
+        let bot0: Bot = "bot 0 gives low to output 2 and high to output 0".parse().unwrap();
+        let bot1: Bot = "bot 1 gives low to output 1 and high to bot 0".parse().unwrap();
+        bots.insert(bot0.number, bot0);
+        bots.insert(bot1.number, bot1);
+        bots
+    }
+
+    #[test]
+    fn it_takes_chips() {
+        let mut bots = get_bots();
+        let mut outputs = HashMap::new();
+        let mut bot: Bot = "bot 2 gives low to bot 1 and high to bot 0".parse().unwrap();
+        bot.take(4, &mut bots, &mut outputs);
+        bot.take(2, &mut bots, &mut outputs);
+        assert_eq!(bot.chips, None);
+    }
+
+    #[test]
+    fn it_takes_2_chips_and_gives_to_low_bot() {
+        let mut outputs = HashMap::new();
+        let mut bots = get_bots();
+        let mut bot: Bot = "bot 2 gives low to bot 1 and high to bot 0".parse().unwrap();
+        bot.take(4, &mut bots, &mut outputs);
+        bot.take(2, &mut bots, &mut outputs);
+
+        assert_eq!(Some(2), bots.get(&1).unwrap().chips);
+    }
+
+    #[test]
+    fn it_takes_2_chips_and_gives_to_high_bot() {
+        let mut outputs = HashMap::new();
+        let mut bots = get_bots();
+        let mut bot: Bot = "bot 2 gives low to bot 1 and high to bot 0".parse().unwrap();
+        bot.take(4, &mut bots, &mut outputs);
+        bot.take(2, &mut bots, &mut outputs);
+
+        assert_eq!(Some(4), bots.get(&0).unwrap().chips);
+    }
+
+    #[test]
+    fn it_takes_2_chips_and_outputs_buckets() {
+        let mut bots = HashMap::new();
+        let mut outputs = HashMap::new();
+        let mut bot: Bot = "bot 0 gives low to output 2 and high to output 0".parse().unwrap();
+        bot.take(4, &mut bots, &mut outputs);
+        bot.take(2, &mut bots, &mut outputs);
+        assert_eq!(outputs.get(&2).unwrap(), &2);
+        assert_eq!(outputs.get(&0).unwrap(), &4);
+    }
+
+    #[test]
+    fn it_builds_bot_list() {
+        let input = "value 5 goes to bot 2
+            bot 2 gives low to bot 1 and high to bot 0
+            value 3 goes to bot 1
+            bot 1 gives low to output 1 and high to bot 0
+            bot 0 gives low to output 2 and high to output 0
+            value 2 goes to bot 2";
+
+        let mut bots = HashMap::new();
+        let mut instructions = vec![];
+        parse(input, &mut bots, &mut instructions).unwrap();
+        assert_eq!(bots.get(&0).unwrap().number, 0);
+        assert_eq!(bots.get(&1).unwrap().number, 1);
+        assert_eq!(bots.get(&2).unwrap().number, 2);
+    }
+
+    #[test]
+    fn it_builds_instruction_list() {
+        let input = "value 5 goes to bot 2
+            bot 2 gives low to bot 1 and high to bot 0
+            value 3 goes to bot 1
+            bot 1 gives low to output 1 and high to bot 0
+            bot 0 gives low to output 2 and high to output 0
+            value 2 goes to bot 2";
+
+        let mut bots = HashMap::new();
+        let mut instructions = vec![];
+        parse(input, &mut bots, &mut instructions).unwrap();
+        let output = vec![
+            (2, 5),
+            (1, 3),
+            (2, 2),
+        ];
+        assert_eq!(output, instructions);
+    }
+
+    #[test]
+    fn it_works() {
+        let input = "value 5 goes to bot 2
+            bot 2 gives low to bot 1 and high to bot 0
+            value 3 goes to bot 1
+            bot 1 gives low to output 1 and high to bot 0
+            bot 0 gives low to output 2 and high to output 0
+            value 2 goes to bot 2";
+
+        let mut bots = HashMap::new();
+        let mut instructions = vec![];
+        parse(input, &mut bots, &mut instructions).unwrap();
+
+        let outputs = work(&mut bots, instructions);
+        assert_eq!(outputs.get(&0).unwrap(), &5);
+        assert_eq!(outputs.get(&1).unwrap(), &2);
+        assert_eq!(outputs.get(&2).unwrap(), &3);
+    }
+
+}
@@ -0,0 +1,39 @@
+use assembunny::{Instruction, Machine, Registers};
+use parse::{parse_lines, ParseError};
+use solution::{Answer, Solution};
+
+pub fn run(instructions: &[Instruction], c: isize) -> Registers {
+    let mut machine = Machine::with_registers(Registers { c, ..Registers::default() });
+    machine.load_instructions(instructions.to_vec());
+    machine.run()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(run(input, 0).a.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(run(input, 1).a.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_runs_with_c_initialised() {
+        let instructions = parse_lines("cpy c a\ninc a").unwrap();
+        assert_eq!(1, run(&instructions, 0).a);
+        assert_eq!(2, run(&instructions, 1).a);
+    }
+}
@@ -0,0 +1,206 @@
+use std::collections::VecDeque;
+use std::collections::HashSet;
+use geometry::Point;
+use parse::{ParseError, Tokens};
+use solution::{Answer, Solution, SolveError};
+
+#[derive(Debug, PartialEq)]
+pub enum Feature {
+    Wall,
+    Open,
+}
+
+// Only for points with no negative coordinates, the rest being outside the
+// building.
+pub fn kind(point: Point, fav_number: usize) -> Feature {
+    let (x, y) = (point.x as usize, point.y as usize);
+    let mut value = (x*x) + (3*x) + (2*x*y) + y + (y*y);
+    value += fav_number;
+    if value.count_ones() % 2 == 0 {
+        Feature::Open
+    } else {
+        Feature::Wall
+    }
+}
+
+const START: Point = Point { x: 1, y: 1 };
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Step {
+    loc: Point,
+    distance: usize,
+}
+
+pub fn neighbours(step: Step, fav_number: usize) -> Vec<Step> {
+    let distance = step.distance + 1;
+    step.loc.neighbours()
+        .filter(|next| next.x >= 0 && next.y >= 0)
+        .filter(|&loc| kind(loc, fav_number) == Feature::Open)
+        .map(|loc| Step { loc, distance })
+        .collect()
+}
+
+pub fn add_unvisited(step: Step, fav_number: usize, to_visit: &mut VecDeque<Step>, visited: &HashSet<Point>) {
+    let maybe = neighbours(step, fav_number);
+    for step in maybe {
+        if !visited.contains(&step.loc) {
+            to_visit.push_back(step);
+        }
+    }
+}
+
+pub fn min_distance(goal: Point, fav_number: usize) -> Option<usize> {
+    let mut to_visit = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    let start = Step { loc: START, distance: 0};
+    visited.insert(START);
+    add_unvisited(start, fav_number, &mut to_visit, &visited);
+
+    while !to_visit.is_empty() {
+        let current = to_visit.pop_front().unwrap();
+        if current.loc == goal {
+            return Some(current.distance);
+        }
+        visited.insert(current.loc);
+        add_unvisited(current, fav_number, &mut to_visit, &visited);
+    }
+    None
+}
+
+pub fn max_loc_visited(max_step: usize, fav_number: usize) -> usize {
+    let mut to_visit = VecDeque::new();
+    let mut visited = HashSet::new();
+
+    let start = Step { loc: START, distance: 0};
+    visited.insert(START);
+    add_unvisited(start, fav_number, &mut to_visit, &visited);
+
+    while !to_visit.is_empty() {
+        let current = to_visit.pop_front().unwrap();
+        if current.distance <= max_step {
+            visited.insert(current.loc);
+            add_unvisited(current, fav_number, &mut to_visit, &visited);
+        }
+    }
+    visited.len()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let number = tokens.next_token("a favourite number")?.parse("a favourite number")?;
+        tokens.finish()?;
+        Ok(number)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        min_distance(Point::new(31, 39), *input)
+            .map(|distance| distance.to_string())
+            .ok_or(SolveError::NoAnswer)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(max_loc_visited(50, *input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn it_detects_walls() {
+        assert_eq!(Feature::Wall, kind(Point::new(1, 0), 10));
+        assert_eq!(Feature::Wall, kind(Point::new(2, 1), 10));
+    }
+
+    #[test]
+    fn it_detects_open() {
+        assert_eq!(Feature::Open, kind(Point::new(1, 1), 10));
+    }
+
+    #[test]
+    fn it_generates_possible_neighbours() {
+        let output = vec![
+            Step { loc: Point::new(3, 3), distance: 1},
+            Step { loc: Point::new(4, 2), distance: 1},
+            Step { loc: Point::new(3, 1), distance: 1},
+            Step { loc: Point::new(2, 2), distance: 1},
+        ];
+        let start = Step { loc: Point::new(3, 2), distance: 0};
+        assert_eq!(output, neighbours(start, 10));
+    }
+
+    #[test]
+    fn it_generates_possible_neighbours_1_1() {
+        let output = vec![
+            Step { loc: Point::new(1, 2), distance: 1},
+            Step { loc: Point::new(0, 1), distance: 1},
+        ];
+        let start = Step { loc: Point::new(1, 1), distance: 0};
+        assert_eq!(output, neighbours(start, 10));
+    }
+
+    #[test]
+    fn it_generates_possible_neighbours_next_to_walls() {
+        let output = vec![
+            Step { loc: Point::new(0, 1), distance: 1},
+        ];
+        let start = Step { loc: Point::new(0, 0), distance: 0};
+        assert_eq!(output, neighbours(start, 10));
+    }
+
+    #[test]
+    fn it_adds_unvisited_neighbours() {
+        let mut to_visit = VecDeque::new();
+        let mut visited = HashSet::new();
+        visited.insert(Point::new(1, 2));
+
+        add_unvisited(Step { loc: Point::new(1, 1), distance: 0}, 10, &mut to_visit, &visited);
+
+        assert_eq!(to_visit.pop_front(), Some(Step { loc: Point::new(0, 1), distance: 1}));
+        assert_eq!(to_visit.len(), 0);
+    }
+
+    #[ignore]
+    #[test]
+    fn it_prints_map() {
+        println!();
+        for y in 0..7 {
+            for x in 0..10 {
+                let feature = match kind(Point::new(x, y), 10) {
+                    Feature::Wall => "#",
+                    Feature::Open => ".",
+                };
+                print!("{}", feature);
+            }
+            println!();
+        }
+        panic!();
+    }
+
+    #[test]
+    fn it_works() {
+        assert_eq!(Some(11), min_distance(Point::new(7, 4), 10));
+    }
+
+    #[test]
+    fn it_has_no_distance_to_unreachable_locations() {
+        assert_eq!(None, min_distance(Point::new(31, 39), 10));
+        assert_eq!(Err(SolveError::NoAnswer), Day13::part1(&10));
+    }
+
+    #[test]
+    fn it_counts_locations_visited() {
+        assert_eq!(5, max_loc_visited(2, 10));
+    }
+
+}
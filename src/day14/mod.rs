@@ -0,0 +1,40 @@
+use parse::{ParseError, Tokens};
+use solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let salt = tokens.next_token("a salt")?.text.to_string();
+        tokens.finish()?;
+        Ok(salt)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut hasher = part1::Hasher::new(input);
+        Ok((0..64).map(|_| hasher.next_key()).last().unwrap().to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut hasher = part2::Hasher::new(input);
+        Ok((0..64).map(|_| hasher.next_key()).last().unwrap().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reports_malformed_salts() {
+        assert_eq!(Ok("abc".to_string()), Day14::parse("abc\n"));
+        assert_eq!(ParseError::new(1, "", "a salt"), Day14::parse("").unwrap_err());
+        assert_eq!(ParseError::new(5, "def", "end of line"), Day14::parse("abc def").unwrap_err());
+    }
+}
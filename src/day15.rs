@@ -0,0 +1,245 @@
+use std::str::FromStr;
+use parse::{parse_lines, ParseError, Tokens};
+use solution::{Answer, Solution};
+
+#[derive(Debug)]
+pub struct Disk {
+    positions: usize,
+    current_position: usize,
+    start: usize,
+}
+
+impl Disk {
+    pub fn tick(&mut self) {
+        self.current_position = (self.current_position + 1) % self.positions;
+    }
+
+    pub fn ball_passes(&self) -> bool {
+        self.current_position == 0
+    }
+
+    pub fn time_travel(&mut self, time: usize) {
+        self.current_position = (self.start + time) % self.positions;
+    }
+}
+
+impl FromStr for Disk {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = Tokens::new(s);
+        tokens.next_token("`Disc`")?.expect("Disc")?;
+        tokens.skip(2)?;
+        let positions_token = tokens.next_token("a number of positions")?;
+        let positions = positions_token.parse("a number of positions")?;
+        if positions == 0 {
+            return Err(positions_token.error("at least one position"));
+        }
+        tokens.skip(7)?;
+        let position_token = tokens.next_token("a starting position")?;
+        let current_position = position_token.text.trim_end_matches('.').parse()
+            .map_err(|_| position_token.error("a starting position"))?;
+        tokens.finish()?;
+
+        Ok(Disk {
+            positions,
+            current_position,
+            start: current_position,
+        })
+    }
+}
+
+pub struct Machine {
+    pub disks: Vec<Disk>,
+    time: usize,
+}
+
+impl Machine {
+    pub fn new(input: &str) -> Result<Machine, ParseError> {
+        Ok(Machine {
+            disks: parse_lines(input)?,
+            time: 0,
+        })
+    }
+
+    pub fn tick(&mut self) {
+        for disk in self.disks.iter_mut() {
+            disk.tick();
+        }
+        self.time += 1;
+    }
+
+    pub fn drop_ball(&mut self) -> bool {
+        for i in 0..self.disks.len() {
+            self.tick();
+            if !self.disks[i].ball_passes() {
+                return false;
+            }
+        }
+        true
+    }
+
+    pub fn drop_time(&mut self) -> usize {
+        let mut time = 0;
+        loop {
+            if self.drop_ball() {
+                return time;
+            }
+            time += 1;
+            self.time_travel(time);
+        }
+    }
+
+    pub fn time_travel(&mut self, time: usize) {
+        self.time = time;
+        for disk in self.disks.iter_mut() {
+            disk.time_travel(time);
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Machine::new(input)?;
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut machine = Machine::new(input)?;
+        Ok(machine.drop_time().to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut machine = Machine::new(input)?;
+        let extra_disk = "Disc #11 has 11 positions; at time=0, it is at position 0.".parse()?;
+        machine.disks.push(extra_disk);
+        Ok(machine.drop_time().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_disk_positions() {
+        let disk: Disk = "Disc #1 has 13 positions; at time=0, it is at position 1.".parse().unwrap();
+        assert_eq!(13, disk.positions);
+    }
+
+    #[test]
+    fn parses_starting_position() {
+        let disk: Disk = "Disc #1 has 13 positions; at time=0, it is at position 1.".parse().unwrap();
+        assert_eq!(1, disk.current_position);
+    }
+
+    #[test]
+    fn disks_tick() {
+        let mut disk: Disk = "Disc #1 has 13 positions; at time=0, it is at position 1.".parse().unwrap();
+        assert_eq!(1, disk.current_position);
+        disk.tick();
+        assert_eq!(2, disk.current_position);
+    }
+
+    #[test]
+    fn disks_tick_over() {
+        let mut disk: Disk = "Disc #1 has 13 positions; at time=0, it is at position 1.".parse().unwrap();
+        disk.current_position = 12;
+        disk.tick();
+        assert_eq!(0, disk.current_position);
+    }
+
+    #[test]
+    fn disk_lets_ball_through() {
+        let mut disk: Disk = "Disc #1 has 13 positions; at time=0, it is at position 1.".parse().unwrap();
+        disk.current_position = 0;
+
+        assert!(disk.ball_passes());
+    }
+
+    #[test]
+    fn disk_doesnt_let_ball_through() {
+        let disk: Disk = "Disc #1 has 13 positions; at time=0, it is at position 1.".parse().unwrap();
+
+        assert!(!disk.ball_passes());
+    }
+
+    #[test]
+    fn it_reports_bad_disks() {
+        let error = "Disc #1 has many positions; at time=0, it is at position 1.".parse::<Disk>().unwrap_err();
+        assert_eq!(ParseError::new(13, "many", "a number of positions"), error);
+        let error = "Disc #1 has 0 positions; at time=0, it is at position 1.".parse::<Disk>().unwrap_err();
+        assert_eq!(ParseError::new(13, "0", "at least one position"), error);
+        let error = "Disc #1 has 13 positions; at time=0".parse::<Disk>().unwrap_err();
+        assert_eq!(36, error.column);
+    }
+
+    fn get_machine() -> Machine {
+        Machine::new(
+            "Disc #1 has 5 positions; at time=0, it is at position 4.
+             Disc #2 has 2 positions; at time=0, it is at position 1."
+             ).unwrap()
+    }
+
+    #[test]
+    fn machine_parses() {
+        let m = get_machine();
+
+        assert_eq!(2, m.disks.len());
+    }
+
+    #[test]
+    fn machine_ticks() {
+        let mut m = get_machine();
+        assert_eq!(0, m.time);
+        m.tick();
+        assert_eq!(1, m.time);
+    }
+
+    #[test]
+    fn machine_ticks_disks() {
+        let mut m = get_machine();
+        m.tick();
+        assert_eq!(0, m.disks[0].current_position);
+    }
+
+    #[test]
+    fn dropping_ball_takes_time() {
+        let mut m = get_machine();
+        m.drop_ball();
+        assert_eq!(2, m.time)
+    }
+
+    #[test]
+    fn dropping_ball_doesnt_succeed() {
+        let mut m = get_machine();
+        let result = m.drop_ball();
+        assert!(!result);
+    }
+
+    #[test]
+    fn dropping_ball_does_succeed() {
+        let mut m = get_machine();
+
+        m.time_travel(5);
+
+        let result = m.drop_ball();
+        assert!(result);
+    }
+
+    #[test]
+    fn finds_first_time_to_drop_ball() {
+        let mut m = get_machine();
+        assert_eq!(5, m.drop_time());
+    }
+
+    #[test]
+    fn it_reports_malformed_disks_when_parsing() {
+        let input = "Disc #1 has 5 positions; at time=0, it is at position 4.\n  Disc #2 has two positions; at time=0, it is at position 1.";
+        assert_eq!("line 2, column 15: expected a number of positions, found `two`", Day15::parse(input).unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,140 @@
+use parse::{ParseError, Tokens};
+use solution::{Answer, Solution};
+
+pub fn gen_dragon(input: &[u8]) -> Vec<u8> {
+    let mut output = vec![];
+    output.extend_from_slice(input);
+    output.push(0);
+    output.extend(swap_bits_and_reverse(input));
+    output
+}
+
+pub fn swap_bits_and_reverse(input: &[u8]) -> Vec<u8> {
+    let mut output = vec![];
+    for bit in input.iter().rev() {
+        match *bit {
+            0 => output.push(1),
+            1 => output.push(0),
+            // `Day16::parse` only lets through 0s and 1s.
+            _ => unreachable!("not a bit: {}", bit),
+        }
+    }
+    output
+}
+
+pub fn checksum(input: &[u8]) -> Vec<u8> {
+    let mut output = vec![];
+    let mut iter = input.iter();
+
+    let mut bit1 = iter.next();
+    let mut bit2 = iter.next();
+    while bit2.is_some() {
+        let val1 = bit1.unwrap();
+        let val2 = bit2.unwrap();
+
+        if val1 + val2 == 1 {
+            output.push(0);
+        } else {
+            output.push(1);
+        }
+
+        bit1 = iter.next();
+        bit2 = iter.next();
+    }
+
+    if output.len() % 2 == 0 {
+        checksum(&output)
+    } else {
+        output
+    }
+}
+
+pub fn split(input: &str) -> Vec<u8> {
+    input.chars().map(|x| (x as u8) - 48).collect()
+}
+
+pub fn fill_and_checksum(input: &str, length: usize) -> String {
+    let mut filled = gen_dragon(&split(input));
+    while filled.len() < length {
+        filled = gen_dragon(&filled);
+    }
+    let checksum = checksum(&filled[0..length]);
+    String::from_utf8(checksum.iter().map(|x| x + 48).collect()).unwrap()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let token = tokens.next_token("an initial state of 0s and 1s")?;
+        tokens.finish()?;
+        if let Some((offset, c)) = token.text.char_indices().find(|&(_, c)| c != '0' && c != '1') {
+            return Err(ParseError::new(token.column + offset, &c.to_string(), "`0` or `1`"));
+        }
+        Ok(token.text.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(fill_and_checksum(input, 272))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(fill_and_checksum(input, 35651584))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_expands_1() {
+        let input = vec![1];
+        let output = vec![1,0,0];
+        assert_eq!(output, gen_dragon(&input));
+    }
+
+    #[test]
+    fn it_expands_0() {
+        let input = vec![0];
+        let output = vec![0,0,1];
+        assert_eq!(output, gen_dragon(&input));
+    }
+
+    #[test]
+    fn it_expands_hard() {
+        let input = vec![1,1,1,1,0,0,0,0,1,0,1,0];
+        let output = vec![1,1,1,1,0,0,0,0,1,0,1,0,0,1,0,1,0,1,1,1,1,0,0,0,0];
+        assert_eq!(output, gen_dragon(&input));
+    }
+
+    #[test]
+    fn it_generates_checksum() {
+        let input = vec![1,1,0,0,1,0,1,1,0,1,0,0];
+        let output = vec![1,0,0];
+        assert_eq!(output, checksum(&input));
+    }
+
+    #[test]
+    fn it_splits() {
+        let output = vec![1,0,0,0,0,0,1,1,1,1,0];
+        assert_eq!(output, split("10000011110"));
+    }
+
+    #[test]
+    fn it_fills_to_checksum() {
+        assert_eq!("01100", fill_and_checksum("10000", 20));
+    }
+
+    #[test]
+    fn it_reports_malformed_states() {
+        assert_eq!(Ok("10000".to_string()), Day16::parse("  10000\n"));
+        assert_eq!(ParseError::new(1, "a", "`0` or `1`"), Day16::parse("abc").unwrap_err());
+        assert_eq!(ParseError::new(3, "2", "`0` or `1`"), Day16::parse("1021").unwrap_err());
+        assert_eq!(ParseError::new(4, "11", "end of line"), Day16::parse("10 11").unwrap_err());
+        assert_eq!(ParseError::new(1, "", "an initial state of 0s and 1s"), Day16::parse("").unwrap_err());
+    }
+}
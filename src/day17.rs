@@ -0,0 +1,305 @@
+use crypto::md5::Md5;
+use crypto::digest::Digest;
+use std::collections::VecDeque;
+use std::fmt;
+use geometry::{Direction, Point};
+use parse::{ParseError, Tokens};
+use solution::{Answer, Solution, SolveError};
+
+// Doors in the order their hash digits come in.
+const DOORS: [Direction; 4] = [Direction::North, Direction::South, Direction::West, Direction::East];
+
+pub struct Path {
+    pub steps: String,
+    passcode: String,
+    hasher: Md5,
+    x: usize,
+    y: usize,
+}
+
+impl Path {
+    pub fn new(passcode: &str) -> Path {
+        Path {
+            steps: String::new(),
+            passcode: passcode.to_string(),
+            hasher: Md5::new(),
+            x: 0,
+            y: 3,
+        }
+    }
+
+    pub fn hash(&mut self) -> Vec<u8> {
+        self.hasher.reset();
+        self.hasher.input_str(&self.passcode);
+        self.hasher.input_str(&self.steps);
+
+        self.hasher.result_str()[0..4]
+            .chars()
+            .map(|x| u8::from_str_radix(&x.to_string(), 16).unwrap())
+            .collect()
+    }
+
+    pub fn open_doors(&mut self) -> Vec<char> {
+        let hashed = self.hash();
+        let position = self.position();
+        DOORS.iter()
+            .zip(hashed)
+            .filter(|&(&direction, hash)| hash > 10 && in_vault(position.step(direction)))
+            .map(|(direction, _)| direction.letter())
+            .collect()
+    }
+
+    pub fn step(&self, direction: char) -> Path {
+        let next = match Direction::from_letter(direction) {
+            Some(direction) => self.position().step(direction),
+            None => panic!("unknown direction"),
+        };
+        let mut new = self.clone();
+        new.steps.push(direction);
+        new.x = next.x as usize;
+        new.y = next.y as usize;
+        new
+    }
+
+    pub fn position(&self) -> Point {
+        Point::new(self.x as i32, self.y as i32)
+    }
+
+    pub fn won(&self) -> bool {
+        self.x == 3 && self.y == 0
+    }
+}
+
+impl Clone for Path {
+    fn clone(&self) -> Path {
+        Path {
+            steps: self.steps.clone(),
+            passcode: self.passcode.clone(),
+            hasher: Md5::new(),
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl fmt::Debug for Path {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "Path {{ x: {}, y: {}, steps: {}, passcode: {} }}", self.x, self.y, self.steps, self.passcode)
+        }
+}
+
+impl PartialEq for Path {
+    fn eq(&self, other: &Path) -> bool {
+        self.x == other.x &&
+            self.y == other.y &&
+            self.passcode == other.passcode &&
+            self.steps == other.steps
+    }
+}
+
+// The vault is four rooms square, with the start in the top left at (0, 3).
+fn in_vault(point: Point) -> bool {
+    point.x >= 0 && point.x < 4 && point.y >= 0 && point.y < 4
+}
+
+pub fn add_possible(location: &mut Path, to_visit: &mut VecDeque<Path>) {
+    for x in location.open_doors() {
+        to_visit.push_back(location.step(x));
+    }
+}
+
+pub fn find_path(passcode: &str) -> Option<Path> {
+    let mut to_visit = VecDeque::new();
+
+    let start = Path::new(passcode);
+    to_visit.push_back(start);
+    while !to_visit.is_empty() {
+        let mut current = to_visit.pop_front().unwrap();
+        if current.won() {
+            return Some(current);
+        }
+        add_possible(&mut current, &mut to_visit);
+    }
+    None
+}
+
+pub fn find_longest_path(passcode: &str) -> Option<Path> {
+    let mut to_visit = VecDeque::new();
+
+    let mut longest: Option<Path> = None;
+    to_visit.push_back(Path::new(passcode));
+    while let Some(mut current) = to_visit.pop_front() {
+        if current.won() {
+            if longest.as_ref().is_none_or(|path| current.steps.len() > path.steps.len()) {
+                longest = Some(current);
+            }
+        } else {
+            add_possible(&mut current, &mut to_visit);
+        }
+    }
+    longest
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let passcode = tokens.next_token("a passcode")?.text.to_string();
+        tokens.finish()?;
+        Ok(passcode)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        find_path(input)
+            .map(|path| path.steps)
+            .ok_or(SolveError::NoAnswer)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        find_longest_path(input)
+            .map(|path| path.steps.len().to_string())
+            .ok_or(SolveError::NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+
+    #[test]
+    fn makes_path() {
+        let p = Path::new("ihgpwlah");
+        assert_eq!("", p.steps);
+        assert_eq!("ihgpwlah", p.passcode);
+    }
+
+    #[test]
+    fn it_hashes() {
+        let mut p = Path::new("hijkl");
+
+        assert_eq!(vec![0xc, 0xe, 0xd, 0x9], p.hash());
+    }
+
+    #[test]
+    fn it_hashes_with_steps() {
+        let mut p = Path::new("hijkl");
+        p.steps = "DR".to_string();
+
+        assert_eq!(vec![5,7,4,5], p.hash());
+    }
+
+    #[test]
+    fn it_generates_open_doors_at_start() {
+        let mut p = Path::new("hijkl");
+        assert_eq!(vec!['D'], p.open_doors());
+    }
+
+    #[test]
+    fn it_generates_open_doors_when_stuck() {
+        let mut p = Path::new("hijkl");
+        p.steps = "DUR".to_string();
+        let output: Vec<char> = vec![];
+        assert_eq!(output, p.open_doors());
+    }
+
+    #[test]
+    fn it_steps() {
+        let start = Path::new("hijkl");
+        let stepped = start.step('D');
+        assert_eq!(2, stepped.y);
+        assert_eq!(0, stepped.x);
+        assert_eq!("D", stepped.steps);
+    }
+
+    #[test]
+    fn it_multi_steps() {
+        let path = Path::new("hijkl").step('D').step('R');
+        assert_eq!("DR", path.steps);
+    }
+
+    #[test]
+    fn it_knows_when_not_won() {
+        let path = Path::new("hijkl");
+        assert!(!path.won());
+    }
+
+    #[test]
+    fn it_knows_when_won() {
+        let mut path = Path::new("hijkl");
+        path.y = 0;
+        path.x = 3;
+        assert!(path.won());
+    }
+
+    #[test]
+    fn it_finds_possible_paths() {
+        let mut start = Path::new("hijkl");
+
+        let mut to_visit = VecDeque::new();
+        add_possible(&mut start, &mut to_visit);
+
+        let next = start.step('D');
+        assert_eq!(Some(next), to_visit.pop_front());
+        assert_eq!(0, to_visit.len());
+    }
+
+    #[test]
+    fn it_has_no_route_when_stuck() {
+        assert_eq!(None, find_path("hijkl"));
+        assert_eq!(Err(SolveError::NoAnswer), Day17::part1(&"hijkl".to_string()));
+    }
+
+    #[test]
+    fn it_finds_shortest_path_easy() {
+        let path = find_path("ihgpwlah").unwrap();
+        assert_eq!("DDRRRD", path.steps);
+    }
+
+    #[test]
+    fn it_finds_shortest_path_medium() {
+        let path = find_path("kglvqrro").unwrap();
+        assert_eq!("DDUDRLRRUDRD", path.steps);
+    }
+
+    #[test]
+    fn it_finds_shortest_path_hard() {
+        let path = find_path("ulqzkmiv").unwrap();
+        assert_eq!("DRURDRUDDLLDLUURRDULRLDUUDDDRR", path.steps);
+    }
+
+    #[test]
+    fn it_has_no_longest_route_when_stuck() {
+        assert_eq!(None, find_longest_path("hijkl"));
+        assert_eq!(Err(SolveError::NoAnswer), Day17::part2(&"hijkl".to_string()));
+    }
+
+    #[test]
+    fn it_finds_longest_path_easy() {
+        let path = find_longest_path("ihgpwlah").unwrap();
+        assert_eq!(370, path.steps.len());
+    }
+
+    #[test]
+    fn it_finds_longest_path_medium() {
+        let path = find_longest_path("kglvqrro").unwrap();
+        assert_eq!(492, path.steps.len());
+    }
+
+    #[test]
+    fn it_finds_longest_path_hard() {
+        let path = find_longest_path("ulqzkmiv").unwrap();
+        assert_eq!(830, path.steps.len());
+    }
+
+    #[test]
+    fn it_reports_malformed_passcodes() {
+        assert_eq!(Ok("hijkl".to_string()), Day17::parse(" hijkl\n"));
+        assert_eq!(ParseError::new(2, "", "a passcode"), Day17::parse(" ").unwrap_err());
+        assert_eq!(ParseError::new(7, "x", "end of line"), Day17::parse("hijkl x").unwrap_err());
+    }
+}
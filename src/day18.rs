@@ -0,0 +1,183 @@
+use parse::ParseError;
+use solution::{Answer, Solution};
+
+pub fn parse(input: &str) -> Result<Vec<bool>, ParseError> {
+    let mut output = Vec::with_capacity(input.len());
+    for (column, x) in input.char_indices() {
+        match x {
+            '.' => output.push(false),
+            '^' => output.push(true),
+            _ => return Err(ParseError::new(column + 1, &x.to_string(), "`.` or `^`")),
+        }
+    }
+    Ok(output)
+}
+
+pub fn unparse(input: &[bool]) -> String {
+    let mut output = String::new();
+    for &x in input {
+        if x {
+            output.push('^');
+        } else {
+            output.push('.');
+        }
+    }
+    output
+}
+
+pub fn next_row(current: &[bool]) -> Vec<bool> {
+    let length = current.len();
+    let mut next = Vec::with_capacity(length);
+    next.push(apply_rule(false, current[0], current[1]));
+    for i in 1..(length - 1) {
+        next.push(apply_rule(current[i - 1], current[i], current[i + 1]));
+    }
+    next.push(apply_rule(current[length - 2], current[length - 1], false));
+
+    next
+}
+
+#[allow(clippy::nonminimal_bool)]
+pub fn apply_rule(left: bool, middle: bool, right: bool) -> bool {
+    (left && middle && !right) ||
+        (!left && middle && right) ||
+        (left && !middle && !right) ||
+        (!left && !middle && right)
+}
+
+pub fn rows(start: Vec<bool>, height: usize) -> Vec<Vec<bool>> {
+    let mut output = Vec::with_capacity(height);
+    output.push(start);
+    for i in 0..(height - 1) {
+        let next = next_row(&output[i]);
+        output.push(next);
+    }
+    output
+}
+
+pub fn print_board(input: &Vec<Vec<bool>>) {
+    println!();
+    for row in input {
+        println!("{}", unparse(row));
+    }
+}
+
+pub fn count_safe(input: &Vec<Vec<bool>>) -> usize {
+    let mut count = 0;
+    for row in input {
+        for &tile in row {
+            if !tile {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<bool>;
+
+    // A single row at least two tiles wide, as `next_row` looks at both
+    // neighbours of each tile.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rows = input.lines()
+            .enumerate()
+            .filter(|&(_, line)| !line.trim().is_empty());
+        let (index, line) = rows.next().ok_or_else(|| ParseError::new(1, "", "a row of tiles"))?;
+        let trimmed = line.trim();
+        let indent = line.len() - line.trim_start().len();
+        let row = parse(trimmed).map_err(|e| e.shifted(indent).on_line(index + 1))?;
+        if row.len() < 2 {
+            return Err(ParseError::new(indent + 2, "", "at least 2 tiles").on_line(index + 1));
+        }
+        if let Some((index, line)) = rows.next() {
+            let indent = line.len() - line.trim_start().len();
+            return Err(ParseError::new(indent + 1, line.trim(), "a single row").on_line(index + 1));
+        }
+        Ok(row)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(count_safe(&rows(input.clone(), 40)).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(count_safe(&rows(input.clone(), 400000)).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_row() {
+        let input = "..^^.";
+        let output = vec![false, false, true, true, false];
+        assert_eq!(Ok(output), parse(input));
+    }
+
+    #[test]
+    fn it_unparses_row() {
+        let output = "..^^.";
+        let input = vec![false, false, true, true, false];
+        assert_eq!(output, unparse(&input));
+    }
+
+    #[test]
+    fn it_generates_next_row() {
+        let input = parse(".^^.^.^^^^").unwrap();
+        let output = parse("^^^...^..^").unwrap();
+        assert_eq!(output, next_row(&input));
+    }
+
+    #[test]
+    fn it_applies_rule() {
+        assert!(apply_rule(true, true, false));
+        assert!(apply_rule(false, true, true));
+        assert!(apply_rule(true, false, false));
+        assert!(apply_rule(false, false, true));
+        assert!(!apply_rule(true, false, true));
+        assert!(!apply_rule(false, true, false));
+    }
+
+    #[test]
+    fn it_generates_n_rows() {
+        let start = parse(".^^.^.^^^^").unwrap();
+        let output = vec![
+            parse(".^^.^.^^^^").unwrap(),
+            parse("^^^...^..^").unwrap(),
+            parse("^.^^.^.^^.").unwrap(),
+            parse("..^^...^^^").unwrap(),
+            parse(".^^^^.^^.^").unwrap(),
+            parse("^^..^.^^..").unwrap(),
+            parse("^^^^..^^^.").unwrap(),
+            parse("^..^^^^.^^").unwrap(),
+            parse(".^^^..^.^^").unwrap(),
+            parse("^^.^^^..^^").unwrap(),
+        ];
+        let board = rows(start, 10);
+        assert_eq!(10, board.len());
+        print_board(&board);
+        assert_eq!(output, board);
+    }
+
+    #[test]
+    fn it_counts_safe() {
+        let start = parse(".^^.^.^^^^").unwrap();
+        let board = rows(start, 10);
+        let safe = count_safe(&board);
+        assert_eq!(38, safe);
+    }
+
+    #[test]
+    fn it_reports_malformed_rows() {
+        assert_eq!(Ok(vec![false, true]), Day18::parse("\n  .^\n\n"));
+        assert_eq!("line 1, column 3: expected `.` or `^`, found `x`", Day18::parse(".^x.").unwrap_err().to_string());
+        assert_eq!(ParseError::new(2, "", "at least 2 tiles"), Day18::parse("^").unwrap_err());
+        assert_eq!(ParseError::new(1, "", "a row of tiles"), Day18::parse(" \n").unwrap_err());
+        assert_eq!(ParseError::new(1, "^^", "a single row").on_line(2), Day18::parse("..\n^^").unwrap_err());
+    }
+}
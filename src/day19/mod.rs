@@ -0,0 +1,42 @@
+use parse::{ParseError, Tokens};
+use solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let token = tokens.next_token("a number of elves")?;
+        let number = token.parse("a number of elves")?;
+        if number == 0 {
+            return Err(token.error("a positive number of elves"));
+        }
+        tokens.finish()?;
+        Ok(number)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(part1::Ring::new(*input).winner().to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(part2::Ring::new(*input).winner().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_needs_some_elves() {
+        assert_eq!(Ok(5), Day19::parse("5\n"));
+        assert_eq!(ParseError::new(1, "0", "a positive number of elves"), Day19::parse("0").unwrap_err());
+        assert_eq!(ParseError::new(1, "-1", "a number of elves"), Day19::parse("-1").unwrap_err());
+    }
+}
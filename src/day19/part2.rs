@@ -0,0 +1,44 @@
+use std::collections::VecDeque;
+
+pub struct Ring {
+    available: Vec<usize>,
+}
+
+impl Ring {
+    pub fn new(size: usize) -> Ring {
+        let mut available = Vec::with_capacity(size);
+        for x in 0..size {
+            available.push(x + 1);
+        }
+        Ring {
+            available,
+        }
+    }
+
+    pub fn winner(&mut self) -> usize {
+        // split the circle in two so the elf across from the front of `left`
+        // is always the front of `right`
+        let half = self.available.len() / 2;
+        let mut left: VecDeque<usize> = self.available[..half].iter().cloned().collect();
+        let mut right: VecDeque<usize> = self.available[half..].iter().cloned().collect();
+        while left.len() + right.len() > 1 {
+            right.pop_front();
+            right.push_back(left.pop_front().unwrap());
+            if right.len() > left.len() + 1 {
+                left.push_back(right.pop_front().unwrap());
+            }
+        }
+        *right.front().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn winning_elf() {
+        let mut ring = Ring::new(5);
+        assert_eq!(2, ring.winner());
+    }
+}
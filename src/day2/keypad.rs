@@ -0,0 +1,290 @@
+use std::collections::VecDeque;
+use std::str::FromStr;
+use geometry::{Direction, Point};
+use parse::ParseError;
+
+pub const SQUARE: &str = "123
+456
+789";
+
+pub const DIAMOND: &str = "  1
+ 234
+56789
+ ABC
+  D";
+
+// The order directions are tried in, so ties between shortest paths go to
+// U, then D, L and R.
+pub const DIRECTIONS: [Direction; 4] = [Direction::North, Direction::South, Direction::West, Direction::East];
+
+// Keys are stored by index, with the key each direction leads to worked out
+// up front. Moving off the edge or into a hole leaves you where you are.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Keypad {
+    keys: Vec<char>,
+    positions: Vec<Point>,
+    neighbours: Vec<[usize; 4]>,
+}
+
+impl Keypad {
+    pub fn keys(&self) -> &[char] {
+        &self.keys
+    }
+
+    pub fn index(&self, key: char) -> Option<usize> {
+        self.keys.iter().position(|&k| k == key)
+    }
+
+    pub fn key(&self, index: usize) -> char {
+        self.keys[index]
+    }
+
+    // Rows run down the layout, so the first row is at y = 0 and the ones
+    // below it are further south.
+    pub fn position(&self, index: usize) -> Point {
+        self.positions[index]
+    }
+
+    pub fn neighbour(&self, index: usize, direction: Direction) -> usize {
+        self.neighbours[index][direction as usize]
+    }
+
+    pub fn step(&self, key: char, direction: char) -> Option<char> {
+        let index = self.index(key)?;
+        let direction = Direction::from_letter(direction)?;
+        Some(self.key(self.neighbour(index, direction)))
+    }
+
+    pub fn follow(&self, start: usize, directions: &str) -> Result<usize, ParseError> {
+        let mut current = start;
+        for (column, c) in directions.chars().enumerate() {
+            current = self.neighbour(current, direction(c, column + 1)?);
+        }
+        Ok(current)
+    }
+
+    pub fn decode_map(&self, start: char, map: &str) -> Result<String, ParseError> {
+        let mut position = self.start(start)?;
+        let mut code = String::new();
+        for (index, line) in map.lines().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let indent = line.len() - trimmed.len();
+            position = self.follow(position, trimmed.trim_end())
+                .map_err(|e| e.shifted(indent).on_line(index + 1))?;
+            code.push(self.key(position));
+        }
+        Ok(code)
+    }
+
+    // The start key isn't part of the map, so there is no position to give.
+    fn start(&self, start: char) -> Result<usize, ParseError> {
+        self.index(start).ok_or_else(|| ParseError::new(1, &start.to_string(), "a start key on the keypad"))
+    }
+
+    // The shortest non-empty line of directions from `from` to `to`, if `to`
+    // can be reached at all. Lines are never empty, since a blank line doesn't
+    // press a key, so pressing the same key again walks into an edge or steps
+    // away and back.
+    pub fn shortest_path(&self, from: usize, to: usize) -> Option<String> {
+        let mut previous: Vec<Option<(usize, Direction)>> = vec![None; self.keys.len()];
+        let mut queue = VecDeque::new();
+        for &direction in DIRECTIONS.iter() {
+            let next = self.neighbour(from, direction);
+            if previous[next].is_none() {
+                previous[next] = Some((from, direction));
+                queue.push_back(next);
+            }
+        }
+        while let Some(current) = queue.pop_front() {
+            if current == to {
+                break;
+            }
+            for &direction in DIRECTIONS.iter() {
+                let next = self.neighbour(current, direction);
+                if previous[next].is_none() {
+                    previous[next] = Some((current, direction));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        previous[to]?;
+        let mut path = vec![];
+        let mut current = to;
+        while let Some((before, direction)) = previous[current] {
+            path.push(direction.letter());
+            if before == from {
+                break;
+            }
+            current = before;
+        }
+        Some(path.iter().rev().collect())
+    }
+
+    // Lines of directions that `decode_map` turns back into `code`.
+    pub fn encode(&self, start: char, code: &str) -> Result<Vec<String>, ParseError> {
+        let mut position = self.start(start)?;
+        let mut lines = vec![];
+        for (column, key) in code.chars().enumerate() {
+            let error = |expected: &str| ParseError::new(column + 1, &key.to_string(), expected);
+            let target = self.index(key).ok_or_else(|| error("a key on the keypad"))?;
+            let path = self.shortest_path(position, target)
+                .ok_or_else(|| error(&format!("a key reachable from `{}`", self.key(position))))?;
+            lines.push(path);
+            position = target;
+        }
+        Ok(lines)
+    }
+}
+
+pub fn direction(c: char, column: usize) -> Result<Direction, ParseError> {
+    Direction::from_letter(c).ok_or_else(|| ParseError::new(column, &c.to_string(), "U, D, L or R"))
+}
+
+// Every character other than a space is a key, laid out on a grid of rows
+// and columns.
+impl FromStr for Keypad {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut keys = vec![];
+        let mut positions = vec![];
+        for (row, line) in s.lines().enumerate() {
+            for (column, key) in line.chars().enumerate() {
+                if key == ' ' {
+                    continue;
+                }
+                if keys.contains(&key) {
+                    return Err(ParseError::new(column + 1, &key.to_string(), "a key not already on the keypad").on_line(row + 1));
+                }
+                keys.push(key);
+                positions.push(Point::new(column as i32, -(row as i32)));
+            }
+        }
+        if keys.is_empty() {
+            return Err(ParseError::new(1, "", "a key"));
+        }
+
+        let neighbours = positions.iter()
+            .enumerate()
+            .map(|(index, point)| {
+                let mut neighbours = [index; 4];
+                for &direction in DIRECTIONS.iter() {
+                    let next = point.step(direction);
+                    if let Some(neighbour) = positions.iter().position(|&p| p == next) {
+                        neighbours[direction as usize] = neighbour;
+                    }
+                }
+                neighbours
+            })
+            .collect();
+
+        Ok(Keypad {
+            keys,
+            positions,
+            neighbours,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "ULL
+        RRDDD
+        LURDL
+        UUUUD";
+
+    #[test]
+    fn it_parses_layouts() {
+        let keypad: Keypad = DIAMOND.parse().unwrap();
+        assert_eq!(13, keypad.keys().len());
+        assert_eq!(Some('3'), keypad.step('1', 'D'));
+        assert_eq!(Some('1'), keypad.step('1', 'L'));
+        assert_eq!(Some('5'), keypad.step('5', 'U'));
+        assert_eq!(Some('B'), keypad.step('D', 'U'));
+        assert_eq!(None, keypad.step('Z', 'U'));
+        assert_eq!(None, keypad.step('5', 'X'));
+    }
+
+    #[test]
+    fn it_decodes_maps_on_any_layout() {
+        assert_eq!(Ok("1985".to_string()), SQUARE.parse::<Keypad>().unwrap().decode_map('5', MAP));
+        assert_eq!(Ok("5DB3".to_string()), DIAMOND.parse::<Keypad>().unwrap().decode_map('5', MAP));
+        let wide: Keypad = "abcd\nef gh".parse().unwrap();
+        assert_eq!(Ok("gcf".to_string()), wide.decode_map('a', "RRRD\nUL\nLLDRR"));
+    }
+
+    #[test]
+    fn it_reports_bad_directions() {
+        let keypad: Keypad = SQUARE.parse().unwrap();
+        let error = keypad.decode_map('5', "UL\n  RX").unwrap_err();
+        assert_eq!("line 2, column 4: expected U, D, L or R, found `X`", error.to_string());
+    }
+
+    #[test]
+    fn it_finds_shortest_paths() {
+        let keypad: Keypad = SQUARE.parse().unwrap();
+        let index = |key| keypad.index(key).unwrap();
+        assert_eq!(Some("DDRR".to_string()), keypad.shortest_path(index('1'), index('9')));
+        assert_eq!(Some("U".to_string()), keypad.shortest_path(index('1'), index('1')));
+        assert_eq!(Some("UD".to_string()), keypad.shortest_path(index('5'), index('5')));
+    }
+
+    #[test]
+    fn it_encodes_codes() {
+        let keypad: Keypad = SQUARE.parse().unwrap();
+        assert_eq!(Ok(vec!["UL".to_string(), "DDRR".to_string(), "D".to_string()]), keypad.encode('5', "199"));
+        assert_eq!("line 1, column 2: expected a key on the keypad, found `A`", keypad.encode('5', "1A").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn it_round_trips_every_pair_of_keys() {
+        for layout in &[SQUARE, DIAMOND, "abcd\nef gh"] {
+            let keypad: Keypad = layout.parse().unwrap();
+            for &from in keypad.keys() {
+                for &to in keypad.keys() {
+                    let code = format!("{}{}", from, to);
+                    let start = keypad.key(0);
+                    let lines = keypad.encode(start, &code).unwrap();
+                    assert_eq!(Ok(code.clone()), keypad.decode_map(start, &lines.join("\n")));
+
+                    let (a, b) = (keypad.position(keypad.index(from).unwrap()), keypad.position(keypad.index(to).unwrap()));
+                    let distance = a.manhattan(&b) as usize;
+                    if *layout == SQUARE && from != to {
+                        assert_eq!(distance, lines[1].len(), "{}", code);
+                    }
+                    assert!(lines[1].len() >= distance.max(1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn it_reports_keys_it_cannot_reach() {
+        let keypad: Keypad = "ab d\nc".parse().unwrap();
+        let index = |key| keypad.index(key).unwrap();
+        assert_eq!(None, keypad.shortest_path(index('a'), index('d')));
+        assert_eq!(Some("U".to_string()), keypad.shortest_path(index('d'), index('d')));
+        let error = keypad.encode('a', "cd").unwrap_err();
+        assert_eq!("line 1, column 2: expected a key reachable from `c`, found `d`", error.to_string());
+    }
+
+    #[test]
+    fn it_reports_start_keys_not_on_the_keypad() {
+        let keypad: Keypad = SQUARE.parse().unwrap();
+        let error = ParseError::new(1, "0", "a start key on the keypad");
+        assert_eq!(Err(error.clone()), keypad.decode_map('0', "U"));
+        assert_eq!(Err(error), keypad.encode('0', "1"));
+    }
+
+    #[test]
+    fn it_rejects_duplicate_keys() {
+        assert_eq!(ParseError::new(2, "1", "a key not already on the keypad").on_line(2), "12\n31".parse::<Keypad>().unwrap_err());
+        assert!(" \n".parse::<Keypad>().is_err());
+    }
+}
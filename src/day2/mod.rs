@@ -0,0 +1,25 @@
+use parse::ParseError;
+use solution::{Answer, Solution};
+
+pub mod keypad;
+pub mod part1;
+pub mod part2;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        part1::KEYPAD.decode_map('5', input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(part1::KEYPAD.decode_map('5', input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(part2::KEYPAD.decode_map('5', input)?)
+    }
+}
@@ -0,0 +1,125 @@
+use day2::keypad::{self, Keypad};
+use parse::ParseError;
+
+lazy_static! {
+    pub static ref KEYPAD: Keypad = keypad::SQUARE.parse().unwrap();
+}
+
+pub fn decode_map(map: &str) -> Result<String, ParseError> {
+    KEYPAD.decode_map('5', map)
+}
+
+pub fn follow_directions(start: i32, directions: &str) -> Result<i32, ParseError> {
+    let mut current = start;
+    for (column, direction) in parse_directions(directions).into_iter().enumerate() {
+        current = follow_direction(current, direction).map_err(|e| e.shifted(column))?;
+    }
+    Ok(current)
+}
+
+pub fn parse_directions(directions: &str) -> Vec<char> {
+    directions.trim().chars()
+        .collect::<Vec<char>>()
+}
+
+pub fn follow_direction(start: i32, direction: char) -> Result<i32, ParseError> {
+    let not_a_key = || ParseError::new(1, &start.to_string(), "a key on the keypad");
+    let key = std::char::from_digit(start as u32, 10).filter(|_| start >= 0).ok_or_else(not_a_key)?;
+    let index = KEYPAD.index(key).ok_or_else(not_a_key)?;
+    let next = KEYPAD.key(KEYPAD.neighbour(index, keypad::direction(direction, 1)?));
+    next.to_digit(10)
+        .map(|digit| digit as i32)
+        .ok_or_else(|| ParseError::new(1, &next.to_string(), "a digit key"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn it_parses_directions() {
+        let input = "ULL";
+        let output = vec!['U', 'L', 'L'];
+
+        assert_eq!(output, parse_directions(input));
+    }
+
+    #[test]
+    fn it_follows_up_direction() {
+        let direction = 'U';
+
+        assert_eq!(Ok(2), follow_direction(5, direction));
+
+        assert_eq!(Ok(6), follow_direction(9, direction));
+
+        assert_eq!(Ok(1), follow_direction(1, direction));
+    }
+
+    #[test]
+    fn it_follows_down_direction() {
+        let direction = 'D';
+
+        assert_eq!(Ok(9), follow_direction(6, direction));
+
+        assert_eq!(Ok(9), follow_direction(9, direction));
+
+        assert_eq!(Ok(5), follow_direction(2, direction));
+    }
+
+    #[test]
+    fn it_follows_left_direction() {
+        let direction = 'L';
+
+        assert_eq!(Ok(4), follow_direction(5, direction));
+
+        assert_eq!(Ok(1), follow_direction(1, direction));
+
+        assert_eq!(Ok(8), follow_direction(9, direction));
+    }
+
+    #[test]
+    fn it_follows_right_direction() {
+        let direction = 'R';
+
+        assert_eq!(Ok(6), follow_direction(5, direction));
+
+        assert_eq!(Ok(3), follow_direction(3, direction));
+    }
+
+    #[test]
+    fn it_follow_multiple_directions_in_square() {
+        let directions = "URDL";
+
+        assert_eq!(Ok(5), follow_directions(5, directions));
+
+        assert_eq!(Ok(5), follow_directions(3, directions));
+    }
+
+    #[test]
+    fn it_follows_directions_in_line() {
+        let directions = "UU";
+
+        assert_eq!(Ok(1), follow_directions(7, directions));
+
+        assert_eq!(Ok(1), follow_directions(1, directions));
+    }
+
+    #[test]
+    fn it_decodes_map() {
+        let map = "ULL
+        RRDDD
+        LURDL
+        UUUUD";
+
+        assert_eq!(Ok("1985".to_string()), decode_map(map));
+    }
+
+    #[test]
+    fn it_reports_bad_directions_and_keys() {
+        assert_eq!(ParseError::new(3, "X", "U, D, L or R"), follow_directions(5, "UUXD").unwrap_err());
+        assert_eq!(ParseError::new(1, "0", "a key on the keypad"), follow_direction(0, 'U').unwrap_err());
+        assert_eq!(ParseError::new(1, "-1", "a key on the keypad"), follow_direction(-1, 'U').unwrap_err());
+        assert_eq!("line 2, column 2: expected U, D, L or R, found `?`", decode_map("U\nL?").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,119 @@
+use day2::keypad::{self, Keypad};
+use parse::ParseError;
+
+lazy_static! {
+    pub static ref KEYPAD: Keypad = keypad::DIAMOND.parse().unwrap();
+}
+
+pub fn decode_map(map: &str) -> Result<String, ParseError> {
+    KEYPAD.decode_map('5', map)
+}
+
+pub fn follow_directions(start: char, directions: &str) -> Result<char, ParseError> {
+    let mut current = start;
+    for (column, direction) in parse_directions(directions).into_iter().enumerate() {
+        current = follow_direction(current, direction).map_err(|e| e.shifted(column))?;
+    }
+    Ok(current)
+}
+
+pub fn parse_directions(directions: &str) -> Vec<char> {
+    directions.trim().chars()
+        .collect::<Vec<char>>()
+}
+
+pub fn follow_direction(start: char, direction: char) -> Result<char, ParseError> {
+    let index = KEYPAD.index(start)
+        .ok_or_else(|| ParseError::new(1, &start.to_string(), "a key on the keypad"))?;
+    Ok(KEYPAD.key(KEYPAD.neighbour(index, keypad::direction(direction, 1)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn it_parses_directions() {
+        let input = "ULL";
+        let output = vec!['U', 'L', 'L'];
+
+        assert_eq!(output, parse_directions(input));
+    }
+
+    #[test]
+    fn it_follows_up_direction() {
+        let direction = 'U';
+
+        assert_eq!(Ok('5'), follow_direction('5', direction));
+
+        assert_eq!(Ok('9'), follow_direction('9', direction));
+
+        assert_eq!(Ok('1'), follow_direction('1', direction));
+    }
+
+    #[test]
+    fn it_follows_down_direction() {
+        let direction = 'D';
+
+        assert_eq!(Ok('A'), follow_direction('6', direction));
+
+        assert_eq!(Ok('9'), follow_direction('9', direction));
+
+        assert_eq!(Ok('6'), follow_direction('2', direction));
+    }
+
+    #[test]
+    fn it_follows_left_direction() {
+        let direction = 'L';
+
+        assert_eq!(Ok('5'), follow_direction('5', direction));
+
+        assert_eq!(Ok('1'), follow_direction('1', direction));
+
+        assert_eq!(Ok('8'), follow_direction('9', direction));
+    }
+
+    #[test]
+    fn it_follows_right_direction() {
+        let direction = 'R';
+
+        assert_eq!(Ok('6'), follow_direction('5', direction));
+
+        assert_eq!(Ok('4'), follow_direction('3', direction));
+    }
+
+    #[test]
+    fn it_follow_multiple_directions_in_square() {
+        let directions = "URDL";
+
+        assert_eq!(Ok('A'), follow_directions('5', directions));
+
+        assert_eq!(Ok('2'), follow_directions('3', directions));
+    }
+
+    #[test]
+    fn it_follows_directions_in_line() {
+        let directions = "UU";
+
+        assert_eq!(Ok('1'), follow_directions('7', directions));
+
+        assert_eq!(Ok('1'), follow_directions('1', directions));
+    }
+
+    #[test]
+    fn it_decodes_map() {
+        let map = "ULL
+        RRDDD
+        LURDL
+        UUUUD";
+
+        assert_eq!(Ok("5DB3".to_string()), decode_map(map));
+    }
+
+    #[test]
+    fn it_reports_bad_directions_and_keys() {
+        assert_eq!(ParseError::new(2, "x", "U, D, L or R"), follow_directions('5', "Rx").unwrap_err());
+        assert_eq!(ParseError::new(1, "E", "a key on the keypad"), follow_direction('E', 'U').unwrap_err());
+    }
+}
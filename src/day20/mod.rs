@@ -0,0 +1,31 @@
+use parse::{parse_lines, ParseError};
+use solution::{Answer, Solution, SolveError};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<part2::Range>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let ranges = input.iter().map(|range| (range.start, range.end)).collect::<Vec<_>>();
+        let merged_blocks = part1::merge_ranges(part1::merge_overlapping(&ranges));
+        match merged_blocks.first() {
+            Some(&(0, 4294967295)) => Err(SolveError::NoAnswer),
+            Some(&(0, end)) => Ok((end + 1).to_string()),
+            _ => Ok("0".to_string()),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let sorted_ranges = part2::merge_range(input);
+        let cleaned_ranges = part2::merge_range(&sorted_ranges);
+        Ok(part2::count_allowed(&cleaned_ranges, 4294967295).to_string())
+    }
+}
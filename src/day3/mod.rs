@@ -0,0 +1,32 @@
+use parse::ParseError;
+use solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+pub mod report;
+pub mod triangle;
+
+use self::triangle::{is_valid, parse_triangles, Layout};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    // The layout only matters once we know which part is asked for, so the
+    // input is kept as text and checked as rows up front.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_triangles(input, Layout::Rows)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let triangles = parse_triangles(input, Layout::Rows)?;
+        Ok(triangles.iter().filter(|sides| is_valid(sides)).count().to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let triangles = parse_triangles(input, Layout::Columns)?;
+        Ok(triangles.iter().filter(|sides| is_valid(sides)).count().to_string())
+    }
+}
@@ -0,0 +1,65 @@
+use day3::triangle::{self, parse_triangles, Layout, Triangle};
+use parse::ParseError;
+
+pub fn count_valid(input: &str) -> Result<usize, ParseError> {
+    Ok(parse_triangles(input, Layout::Rows)?.iter().filter(|sides| is_valid(sides)).count())
+}
+
+pub fn parse(input: &str) -> Result<Triangle, ParseError> {
+    triangle::parse_line(input)
+}
+
+pub fn is_valid(sides: &Triangle) -> bool {
+    triangle::is_valid(sides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_detects_valid_triangles() {
+        let triangle = [5, 10, 12];
+        assert!(is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_detects_invalid_triangles() {
+        let triangle = [5, 10, 25];
+        assert!(!is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_detects_valid_triangles_sides_unordered() {
+        let triangle = [10, 12, 5];
+        assert!(is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_detects_invalid_triangles_sides_unordered() {
+        let triangle = [25, 10, 5];
+        assert!(!is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_parses_input() {
+        let input = "  810  679   10";
+        let expected = [810, 679, 10];
+        assert_eq!(Ok(expected), parse(input));
+    }
+
+    #[test]
+    fn it_counts_valid_triangles() {
+        let input = "  810  679   10\n\
+                        84  910  149\n\
+                       607  425  901\n\
+                       556  616  883";
+        assert_eq!(Ok(2), count_valid(input));
+    }
+
+    #[test]
+    fn it_reports_malformed_lines() {
+        assert_eq!("line 2, column 7: expected end of line, found `4`", count_valid("1 2 3\n1 2 3 4").unwrap_err().to_string());
+        assert_eq!(ParseError::new(3, "", "a side length"), parse("1 ").unwrap_err());
+    }
+}
@@ -0,0 +1,108 @@
+use day3::triangle::{self, parse_triangles, Layout, Triangle};
+use parse::ParseError;
+
+pub fn count_valid(input: &str) -> Result<usize, ParseError> {
+    Ok(parse_input(input)?.iter().filter(|sides| is_valid(sides)).count())
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Triangle>, ParseError> {
+    parse_triangles(input, Layout::Columns)
+}
+
+pub fn parse_line(input: &str) -> Result<Triangle, ParseError> {
+    triangle::parse_line(input)
+}
+
+pub fn transpose(rows: &[Triangle; 3]) -> [Triangle; 3] {
+    triangle::transpose(rows)
+}
+
+pub fn is_valid(sides: &Triangle) -> bool {
+    triangle::is_valid(sides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_detects_valid_triangles() {
+        let triangle = [5, 10, 12];
+        assert!(is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_detects_invalid_triangles() {
+        let triangle = [5, 10, 25];
+        assert!(!is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_detects_valid_triangles_sides_unordered() {
+        let triangle = [10, 12, 5];
+        assert!(is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_detects_invalid_triangles_sides_unordered() {
+        let triangle = [25, 10, 5];
+        assert!(!is_valid(&triangle));
+    }
+
+    #[test]
+    fn it_parses_line_input() {
+        let input = "  810  679   10";
+        let expected = [810, 679, 10];
+        assert_eq!(Ok(expected), parse_line(input));
+    }
+
+    #[test]
+    fn it_parses_input() {
+        let input = "101 301 501\n\
+                     102 302 502\n\
+                     103 303 503\n\
+                     201 401 601\n\
+                     202 402 602\n\
+                     203 403 603\n";
+        let output = vec![
+            [101, 102, 103],
+            [301, 302, 303],
+            [501, 502, 503],
+            [201, 202, 203],
+            [401, 402, 403],
+            [601, 602, 603],
+        ];
+
+        assert_eq!(Ok(output), parse_input(input));
+    }
+
+    #[test]
+    fn it_transposes() {
+        let input = [
+            [1, 2, 3],
+            [4, 5, 6],
+            [7, 8, 9]
+        ];
+        let output = [
+            [1, 4, 7],
+            [2, 5, 8],
+            [3, 6, 9]
+        ];
+
+        assert_eq!(output, transpose(&input));
+    }
+
+    #[test]
+    fn it_counts_valid_triangles() {
+        let input = "  810  679   10\n\
+                        84  910  149\n\
+                       607  425  901\n";
+        assert_eq!(Ok(1), count_valid(input));
+    }
+
+    #[test]
+    fn it_reports_partial_groups() {
+        let error = count_valid("1 2 3\n4 5 6").unwrap_err();
+        assert_eq!("line 1, column 1: expected a group of 3 lines, found `2 of them`", error.to_string());
+    }
+}
@@ -0,0 +1,218 @@
+use std::fmt;
+use std::fmt::Write;
+
+use day3::triangle::{is_valid, Triangle};
+
+// Each triangle gets exactly one kind, tried in this order. With whole
+// number sides a right-angled triangle is always scalene, so it doesn't
+// hide anything to list it separately.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Kind {
+    Invalid,
+    Degenerate,
+    Equilateral,
+    Isosceles,
+    RightAngled,
+    Scalene,
+}
+
+pub const KINDS: [Kind; 6] = [Kind::Invalid, Kind::Degenerate, Kind::Equilateral, Kind::Isosceles, Kind::RightAngled, Kind::Scalene];
+
+impl Kind {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Kind::Invalid => "invalid",
+            Kind::Degenerate => "degenerate",
+            Kind::Equilateral => "equilateral",
+            Kind::Isosceles => "isosceles",
+            Kind::RightAngled => "right-angled",
+            Kind::Scalene => "scalene",
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+fn sorted(sides: &Triangle) -> [u64; 3] {
+    let mut sorted = [u64::from(sides[0]), u64::from(sides[1]), u64::from(sides[2])];
+    sorted.sort_unstable();
+    sorted
+}
+
+pub fn classify(sides: &Triangle) -> Kind {
+    let [a, b, c] = sorted(sides);
+    if a + b < c {
+        Kind::Invalid
+    } else if a + b == c {
+        Kind::Degenerate
+    } else if a == c {
+        Kind::Equilateral
+    } else if a == b || b == c {
+        Kind::Isosceles
+    } else if u128::from(a * a) + u128::from(b * b) == u128::from(c) * u128::from(c) {
+        Kind::RightAngled
+    } else {
+        Kind::Scalene
+    }
+}
+
+pub fn perimeter(sides: &Triangle) -> u64 {
+    sides.iter().map(|&side| u64::from(side)).sum()
+}
+
+// Heron's formula, in the rearrangement that stays accurate for needle-thin
+// triangles. There is no area if the sides don't meet, and degenerate ones
+// come out as zero.
+pub fn area(sides: &Triangle) -> Option<f64> {
+    let [c, b, a] = sorted(sides);
+    if b + c < a {
+        return None;
+    }
+    let (a, b, c) = (a as f64, b as f64, c as f64);
+    let product = (a + (b + c)) * (c - (a - b)) * (c + (a - b)) * (a + (b - c));
+    Some(product.max(0.0).sqrt() / 4.0)
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Classified {
+    pub sides: Triangle,
+    pub kind: Kind,
+    pub perimeter: u64,
+    pub area: Option<f64>,
+}
+
+impl Classified {
+    pub fn new(sides: Triangle) -> Classified {
+        Classified {
+            sides,
+            kind: classify(&sides),
+            perimeter: perimeter(&sides),
+            area: area(&sides),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct PerimeterStats {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Report {
+    pub triangles: Vec<Classified>,
+}
+
+impl Report {
+    pub fn new(triangles: &[Triangle]) -> Report {
+        Report {
+            triangles: triangles.iter().map(|&sides| Classified::new(sides)).collect(),
+        }
+    }
+
+    pub fn push(&mut self, sides: Triangle) {
+        self.triangles.push(Classified::new(sides));
+    }
+
+    pub fn count(&self, kind: Kind) -> usize {
+        self.triangles.iter().filter(|t| t.kind == kind).count()
+    }
+
+    // The same triangles `count_valid` counts.
+    pub fn valid(&self) -> impl Iterator<Item = &Classified> {
+        self.triangles.iter().filter(|t| is_valid(&t.sides))
+    }
+
+    // Over the valid triangles only, as the others aren't triangles at all.
+    pub fn perimeter_stats(&self) -> Option<PerimeterStats> {
+        let perimeters = self.valid().map(|t| t.perimeter).collect::<Vec<_>>();
+        if perimeters.is_empty() {
+            return None;
+        }
+        let total: u64 = perimeters.iter().sum();
+        Some(PerimeterStats {
+            min: *perimeters.iter().min().unwrap(),
+            max: *perimeters.iter().max().unwrap(),
+            mean: total as f64 / perimeters.len() as f64,
+        })
+    }
+
+    pub fn total_area(&self) -> f64 {
+        self.triangles.iter().filter_map(|t| t.area).sum()
+    }
+
+    // One row per triangle, numbered from 1 in input order. Invalid
+    // triangles leave the area empty.
+    pub fn csv(&self) -> String {
+        let mut csv = String::from("index,a,b,c,kind,perimeter,area\n");
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            let [a, b, c] = triangle.sides;
+            let area = triangle.area.map(|area| format!("{:.3}", area)).unwrap_or_default();
+            writeln!(csv, "{},{},{},{},{},{},{}", index + 1, a, b, c, triangle.kind, triangle.perimeter, area).unwrap();
+        }
+        csv
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "triangles: {}", self.triangles.len())?;
+        writeln!(f, "valid: {}", self.valid().count())?;
+        for kind in KINDS.iter() {
+            writeln!(f, "{}: {}", kind, self.count(*kind))?;
+        }
+        if let Some(stats) = self.perimeter_stats() {
+            writeln!(f, "perimeter: min {}, max {}, mean {:.3}", stats.min, stats.max, stats.mean)?;
+        }
+        writeln!(f, "total area: {:.3}", self.total_area())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_classifies_triangles() {
+        assert_eq!(Kind::Invalid, classify(&[5, 10, 25]));
+        assert_eq!(Kind::Degenerate, classify(&[10, 5, 5]));
+        assert_eq!(Kind::Degenerate, classify(&[0, 0, 0]));
+        assert_eq!(Kind::Equilateral, classify(&[7, 7, 7]));
+        assert_eq!(Kind::Isosceles, classify(&[5, 8, 5]));
+        assert_eq!(Kind::RightAngled, classify(&[13, 5, 12]));
+        assert_eq!(Kind::Scalene, classify(&[5, 10, 12]));
+        assert_eq!(Kind::Equilateral, classify(&[u32::MAX, u32::MAX, u32::MAX]));
+    }
+
+    #[test]
+    fn it_measures_triangles() {
+        assert_eq!(Some(6.0), area(&[3, 4, 5]));
+        assert_eq!(Some(0.0), area(&[1, 2, 3]));
+        assert_eq!(None, area(&[1, 2, 4]));
+        assert!((area(&[2, 2, 2]).unwrap() - 3f64.sqrt()).abs() < 1e-12);
+        assert_eq!(12, perimeter(&[3, 4, 5]));
+    }
+
+    #[test]
+    fn it_summarises_reports() {
+        let report = Report::new(&[[3, 4, 5], [5, 10, 25], [6, 8, 10], [2, 2, 3]]);
+        assert_eq!(2, report.count(Kind::RightAngled));
+        assert_eq!(3, report.valid().count());
+        assert_eq!(Some(PerimeterStats { min: 7, max: 24, mean: 43.0 / 3.0 }), report.perimeter_stats());
+        assert_eq!(None, Report::new(&[[1, 1, 5]]).perimeter_stats());
+        assert!(report.to_string().contains("right-angled: 2\n"));
+    }
+
+    #[test]
+    fn it_exports_csv() {
+        let report = Report::new(&[[3, 4, 5], [5, 10, 25]]);
+        assert_eq!("index,a,b,c,kind,perimeter,area\n\
+                    1,3,4,5,right-angled,12,6.000\n\
+                    2,5,10,25,invalid,40,\n", report.csv());
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Lines, Read};
+
+use parse::{ParseError, ReadError, Tokens};
+
+pub type Triangle = [u32; 3];
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Layout {
+    // One triangle per line.
+    Rows,
+    // Each group of three lines holds three triangles, one per column.
+    Columns,
+}
+
+pub fn is_valid(sides: &Triangle) -> bool {
+    let longest = sides.iter().max().unwrap();
+    let total: u64 = sides.iter().map(|&side| u64::from(side)).sum();
+    total - u64::from(*longest) > u64::from(*longest)
+}
+
+pub fn parse_line(line: &str) -> Result<Triangle, ParseError> {
+    let mut tokens = Tokens::new(line);
+    let mut sides = [0; 3];
+    for side in sides.iter_mut() {
+        *side = tokens.next_token("a side length")?.parse("a side length")?;
+    }
+    tokens.finish()?;
+    Ok(sides)
+}
+
+pub fn transpose(rows: &[Triangle; 3]) -> [Triangle; 3] {
+    let mut columns = [[0; 3]; 3];
+    for (i, row) in rows.iter().enumerate() {
+        for (j, &side) in row.iter().enumerate() {
+            columns[j][i] = side;
+        }
+    }
+    columns
+}
+
+// Turns numbered lines into triangles for either layout. Blank lines are
+// skipped, and don't count towards a group of columns.
+pub struct Grouper {
+    layout: Layout,
+    group: Vec<(usize, Triangle)>,
+    ready: VecDeque<Triangle>,
+}
+
+impl Grouper {
+    pub fn new(layout: Layout) -> Grouper {
+        Grouper {
+            layout,
+            group: vec![],
+            ready: VecDeque::new(),
+        }
+    }
+
+    pub fn feed(&mut self, number: usize, line: &str) -> Result<(), ParseError> {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            return Ok(());
+        }
+        let indent = line.len() - trimmed.len();
+        let sides = parse_line(trimmed).map_err(|e| e.shifted(indent).on_line(number))?;
+        match self.layout {
+            Layout::Rows => self.ready.push_back(sides),
+            Layout::Columns => {
+                self.group.push((number, sides));
+                if self.group.len() == 3 {
+                    let rows = [self.group[0].1, self.group[1].1, self.group[2].1];
+                    self.ready.extend(transpose(&rows).iter());
+                    self.group.clear();
+                }
+            },
+        }
+        Ok(())
+    }
+
+    // Fails if the input stopped part way through a group of columns.
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        match self.group.first() {
+            Some(&(number, _)) => {
+                let found = format!("{} of them", self.group.len());
+                self.group.clear();
+                Err(ParseError::new(1, &found, "a group of 3 lines").on_line(number))
+            },
+            None => Ok(()),
+        }
+    }
+
+    pub fn next_triangle(&mut self) -> Option<Triangle> {
+        self.ready.pop_front()
+    }
+}
+
+pub fn parse_triangles(input: &str, layout: Layout) -> Result<Vec<Triangle>, ParseError> {
+    let mut grouper = Grouper::new(layout);
+    for (index, line) in input.lines().enumerate() {
+        grouper.feed(index + 1, line)?;
+    }
+    grouper.finish()?;
+    let mut triangles = vec![];
+    while let Some(triangle) = grouper.next_triangle() {
+        triangles.push(triangle);
+    }
+    Ok(triangles)
+}
+
+// Reads triangles a line at a time, stopping at the first error.
+pub struct Triangles<R: Read> {
+    lines: Lines<BufReader<R>>,
+    grouper: Grouper,
+    line: usize,
+    done: bool,
+}
+
+impl<R: Read> Triangles<R> {
+    pub fn new(reader: R, layout: Layout) -> Triangles<R> {
+        Triangles {
+            lines: BufReader::new(reader).lines(),
+            grouper: Grouper::new(layout),
+            line: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for Triangles<R> {
+    type Item = Result<Triangle, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(triangle) = self.grouper.next_triangle() {
+                return Some(Ok(triangle));
+            }
+            if self.done {
+                return None;
+            }
+            let result = match self.lines.next() {
+                Some(Ok(line)) => {
+                    self.line += 1;
+                    self.grouper.feed(self.line, &line).map_err(ReadError::Parse)
+                },
+                Some(Err(error)) => Err(ReadError::Io(error)),
+                None => {
+                    self.done = true;
+                    self.grouper.finish().map_err(ReadError::Parse)
+                },
+            };
+            if let Err(error) = result {
+                self.done = true;
+                return Some(Err(error));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "101 301 501
+                         102 302 502
+                         103 303 503
+                         201 401 601
+                         202 402 602
+                         203 403 603";
+
+    #[test]
+    fn it_checks_triangles_without_sorting() {
+        assert!(is_valid(&[5, 10, 12]));
+        assert!(is_valid(&[12, 5, 10]));
+        assert!(!is_valid(&[25, 10, 5]));
+        assert!(!is_valid(&[5, 5, 10]));
+        assert!(is_valid(&[u32::MAX, u32::MAX, u32::MAX]));
+    }
+
+    #[test]
+    fn it_reads_rows() {
+        let triangles = parse_triangles(INPUT, Layout::Rows).unwrap();
+        assert_eq!(6, triangles.len());
+        assert_eq!([101, 301, 501], triangles[0]);
+    }
+
+    #[test]
+    fn it_reads_columns() {
+        let triangles = parse_triangles(INPUT, Layout::Columns).unwrap();
+        assert_eq!(vec![[101, 102, 103], [301, 302, 303], [501, 502, 503],
+                        [201, 202, 203], [401, 402, 403], [601, 602, 603]], triangles);
+    }
+
+    #[test]
+    fn it_reports_malformed_lines() {
+        let error = parse_triangles("1 2 3\n  4 x 6", Layout::Rows).unwrap_err();
+        assert_eq!("line 2, column 5: expected a side length, found `x`", error.to_string());
+        let error = parse_triangles("1 2 3 4", Layout::Rows).unwrap_err();
+        assert_eq!("line 1, column 7: expected end of line, found `4`", error.to_string());
+        let error = parse_triangles("1 2", Layout::Rows).unwrap_err();
+        assert_eq!((1, 4), (error.line, error.column));
+    }
+
+    #[test]
+    fn it_reports_partial_groups() {
+        let error = parse_triangles("1 2 3\n4 5 6\n7 8 9\n\n1 1 1\n2 2 2\n", Layout::Columns).unwrap_err();
+        assert_eq!("line 5, column 1: expected a group of 3 lines, found `2 of them`", error.to_string());
+    }
+
+    #[test]
+    fn it_streams_from_readers() {
+        let triangles = Triangles::new(INPUT.as_bytes(), Layout::Columns).collect::<Vec<_>>();
+        assert_eq!(6, triangles.len());
+        assert_eq!([601, 602, 603], *triangles[5].as_ref().unwrap());
+
+        let mut triangles = Triangles::new("1 2 3\n4 5 6".as_bytes(), Layout::Columns);
+        match triangles.next() {
+            Some(Err(ReadError::Parse(error))) => assert_eq!(1, error.line),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!(triangles.next().is_none());
+    }
+}
@@ -0,0 +1,354 @@
+use regex::Regex;
+use std::fmt;
+use std::str::FromStr;
+use parse::{parse_lines, ParseError};
+use solution::{Answer, Solution, SolveError};
+
+pub mod query;
+
+lazy_static! {
+    static ref SECTOR_RE: Regex = Regex::new(r#"^(?P<name>.+)-(?P<sid>\d+)\[(?P<cs>[a-z]+)\]$"#).unwrap();
+}
+
+#[derive(Debug)]
+pub struct RoomCode {
+    pub checksum: String,
+    pub sector_id: u32,
+    pub name: String,
+}
+
+// A name with too few different letters to make a checksum from.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ShortName {
+    pub letters: usize,
+}
+
+impl fmt::Display for ShortName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "only {} different letters in the name, a checksum needs 5", self.letters)
+    }
+}
+
+impl RoomCode {
+    // The five most common letters, most common first and ties broken
+    // alphabetically. Names with fewer than five different letters have no
+    // checksum, so no room code with one can be valid.
+    pub fn common_chars(&self) -> Result<Vec<char>, ShortName> {
+        let mut counts = [0usize; 26];
+        for byte in self.name.bytes().filter(|b| b.is_ascii_lowercase()) {
+            counts[(byte - b'a') as usize] += 1;
+        }
+        let mut letters = (0..26u8).filter(|&i| counts[i as usize] > 0).collect::<Vec<u8>>();
+        if letters.len() < 5 {
+            return Err(ShortName { letters: letters.len() });
+        }
+        letters.sort_by(|&a, &b| counts[b as usize].cmp(&counts[a as usize]).then(a.cmp(&b)));
+        Ok(letters[..5].iter().map(|&i| (b'a' + i) as char).collect())
+    }
+
+    pub fn computed_checksum(&self) -> Result<String, ShortName> {
+        self.common_chars().map(|chars| chars.into_iter().collect())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.computed_checksum().is_ok_and(|checksum| checksum == self.checksum)
+    }
+
+    pub fn sum_valid(codes: Vec<&str>) -> Result<u32, ParseError> {
+        let mut sum = 0;
+        for (index, raw_code) in codes.iter().enumerate() {
+            let code: RoomCode = raw_code.parse().map_err(|e: ParseError| e.on_line(index + 1))?;
+            if code.is_valid() {
+                sum += code.sector_id;
+            }
+        }
+        Ok(sum)
+    }
+
+    pub fn decrypted_name(&self) -> String {
+        caesar_apply(&self.name, self.sector_id)
+    }
+
+    // The reverse of `decrypted_name`: encrypts a name made of lowercase
+    // words separated by single spaces and works out its checksum, which
+    // needs the name to have five different letters.
+    pub fn encode(name: &str, sector_id: u32) -> Result<RoomCode, ParseError> {
+        let mut previous = b' ';
+        for (column, byte) in name.bytes().enumerate() {
+            let letter = byte.is_ascii_lowercase();
+            if !letter && (byte != b' ' || previous == b' ' || column + 1 == name.len()) {
+                let token = name[column..].chars().next().unwrap().to_string();
+                return Err(ParseError::new(column + 1, &token, "a lowercase letter"));
+            }
+            previous = byte;
+        }
+        if name.is_empty() {
+            return Err(ParseError::new(1, "", "a lowercase letter"));
+        }
+        let back = ((26 - sector_id % 26) % 26) as u8;
+        let encrypted = name.bytes()
+            .map(|b| if b == b' ' { b'-' } else { letter_shift(b, back) })
+            .collect::<Vec<u8>>();
+        let mut code = RoomCode {
+            checksum: String::new(),
+            sector_id,
+            name: String::from_utf8(encrypted).unwrap(),
+        };
+        code.checksum = code.computed_checksum()
+            .map_err(|_| ParseError::new(1, name, "a name with at least 5 different letters"))?;
+        Ok(code)
+    }
+}
+
+impl fmt::Display for RoomCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}[{}]", self.name, self.sector_id, self.checksum)
+    }
+}
+
+impl FromStr for RoomCode {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let captures = match SECTOR_RE.captures(s) {
+            Some(captures) => captures,
+            None => return Err(ParseError::new(1, s, "a room code like `name-123[abcde]`")),
+        };
+        let name = captures.name("name").unwrap();
+        if let Some(column) = name.bytes().position(|b| !b.is_ascii_lowercase() && b != b'-') {
+            let token = name[column..].chars().next().unwrap().to_string();
+            return Err(ParseError::new(column + 1, &token, "a lowercase letter or `-`"));
+        }
+        let sid = captures.name("sid").unwrap();
+        let sid_start = captures.pos(2).unwrap().0;
+        Ok(RoomCode{
+            checksum: captures.name("cs").unwrap().to_string(),
+            sector_id: sid.parse().map_err(|_| ParseError::new(sid_start + 1, sid, "a sector id"))?,
+            name: name.to_string(),
+        })
+    }
+}
+
+fn caesar_apply(input: &str, shift: u32) -> String {
+    let adjusted_shift = (shift % 26) as u8;
+    let chars = input
+        .bytes()
+        .map(|x| letter_shift(x, adjusted_shift))
+        .collect::<Vec<u8>>()
+        ;
+    String::from_utf8(chars).unwrap()
+}
+
+// Anything other than a lowercase letter or `-` is left as it is, as `name`
+// can be set without going through `from_str`.
+fn letter_shift(input: u8, shift: u8) -> u8 {
+    if input == 45 { // '-'
+        return 32 // ' '
+    }
+    if !input.is_ascii_lowercase() {
+        return input;
+    }
+    let mut inter = input - 97;
+    inter = (inter + shift) % 26;
+    inter + 97
+}
+
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<RoomCode>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let sum: u32 = input.iter()
+            .filter(|code| code.is_valid())
+            .map(|code| code.sector_id)
+            .sum();
+        Ok(sum.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        query::search(input, &query::Matcher::substring("northpole"))
+            .first()
+            .map(|&(sector_id, _)| sector_id.to_string())
+            .ok_or(SolveError::NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_from_str() {
+        let _: RoomCode = "aaaaa-bbb-z-y-x-123[abxyz]".parse().unwrap();
+    }
+
+    #[test]
+    fn it_reports_malformed_room_codes() {
+        let error = "aaaaa-bbb-z-y-x[abxyz]".parse::<RoomCode>().unwrap_err();
+        assert_eq!(ParseError::new(1, "aaaaa-bbb-z-y-x[abxyz]", "a room code like `name-123[abcde]`"), error);
+        let error = "aaaaa-99999999999[abxyz]".parse::<RoomCode>().unwrap_err();
+        assert_eq!(ParseError::new(7, "99999999999", "a sector id"), error);
+    }
+
+    #[test]
+    fn it_extracts_checksum() {
+        let code: RoomCode = "aaaaa-bbb-z-y-x-123[abxyz]".parse().unwrap();
+        assert_eq!("abxyz", code.checksum);
+    }
+
+    #[test]
+    fn it_extracts_sector_id() {
+        let code: RoomCode = "aaaaa-bbb-z-y-x-123[abxyz]".parse().unwrap();
+        assert_eq!(123, code.sector_id);
+    }
+
+    #[test]
+    fn it_extracts_name() {
+        let code: RoomCode = "aaaaa-bbb-z-y-x-123[abxyz]".parse().unwrap();
+        assert_eq!("aaaaa-bbb-z-y-x", code.name);
+    }
+
+    #[test]
+    fn it_extracts_most_common_chars_easy() {
+        let expteced = vec!['p', 'b', 'x', 'y', 'z'];
+        let code: RoomCode = "ppppp-bbb-z-y-x-123[abxyz]".parse().unwrap();
+        assert_eq!(Ok(expteced), code.common_chars());
+    }
+
+    #[test]
+    fn it_extracts_most_common_chars_hard() {
+        let expteced = vec!['a', 'b', 'c', 'd', 'e'];
+        let code: RoomCode = "a-b-c-d-e-f-g-h-987[abcde]".parse().unwrap();
+        assert_eq!(Ok(expteced), code.common_chars());
+    }
+
+    #[test]
+    fn it_has_no_checksum_for_short_names() {
+        let code: RoomCode = "aaa-bb-c-d-1[abcd]".parse().unwrap();
+        assert_eq!(Err(ShortName { letters: 4 }), code.common_chars());
+        assert!(!code.is_valid());
+        let code: RoomCode = "---1[abcde]".parse().unwrap();
+        assert_eq!(Err(ShortName { letters: 0 }), code.computed_checksum());
+        assert_eq!("only 0 different letters in the name, a checksum needs 5", ShortName { letters: 0 }.to_string());
+    }
+
+    #[test]
+    fn it_rejects_letters_outside_a_to_z() {
+        let error = "abcde-Fgh-1[abcde]".parse::<RoomCode>().unwrap_err();
+        assert_eq!(ParseError::new(7, "F", "a lowercase letter or `-`"), error);
+        let error = "abcde-ßgh-1[abcde]".parse::<RoomCode>().unwrap_err();
+        assert_eq!("line 1, column 7: expected a lowercase letter or `-`, found `ß`", error.to_string());
+    }
+
+    #[test]
+    fn it_computes_checksum() {
+        let expteced = "abcde";
+        let code: RoomCode = "a-b-c-d-e-f-g-h-987[abcde]".parse().unwrap();
+        assert_eq!(Ok(expteced.to_string()), code.computed_checksum());
+    }
+
+    #[test]
+    fn it_validates_valid_checksum() {
+        let code1: RoomCode = "a-b-c-d-e-f-g-h-987[abcde]".parse().unwrap();
+        assert!(code1.is_valid());
+        let code2: RoomCode = "ppppp-bbb-z-y-x-123[pbxyz]".parse().unwrap();
+        assert!(code2.is_valid());
+        let code3: RoomCode = "not-a-real-room-404[oarel]".parse().unwrap();
+        assert!(code3.is_valid());
+    }
+
+    #[test]
+    fn it_doesnt_validates_invalid_checksum() {
+        let code2: RoomCode = "totally-real-room-200[decoy]".parse().unwrap();
+        assert!(!code2.is_valid());
+    }
+
+    #[test]
+    fn it_sums_valid_sector_ids() {
+        let codes = vec![
+            "aaaaa-bbb-z-y-x-123[abxyz]",
+            "a-b-c-d-e-f-g-h-987[abcde]",
+            "not-a-real-room-404[oarel]",
+            "totally-real-room-200[decoy]",
+        ];
+        assert_eq!(Ok(1514), RoomCode::sum_valid(codes));
+        let error = RoomCode::sum_valid(vec!["a-b-c-d-e-1[abcde]", "a-B-c-d-e-1[abcde]"]).unwrap_err();
+        assert_eq!("line 2, column 3: expected a lowercase letter or `-`, found `B`", error.to_string());
+    }
+
+    #[test]
+    fn it_shifts_letters() {
+        assert_eq!(98, letter_shift(97, 1));
+        assert_eq!(104, letter_shift(97, 7));
+        assert_eq!(32, letter_shift(45, 11));
+        assert_eq!(97, letter_shift(122, 1));
+        assert_eq!(b'A', letter_shift(b'A', 3));
+    }
+
+    #[test]
+    fn it_decrypts_caesar_ciphers() {
+        assert_eq!("very encrypted name", caesar_apply("qzmt-zixmtkozy-ivhz", 343));
+    }
+
+    #[test]
+    fn it_decrypts_names() {
+        let code: RoomCode = "qzmt-zixmtkozy-ivhz-343[zimth]".parse().unwrap();
+        assert_eq!("very encrypted name", code.decrypted_name());
+    }
+
+    #[test]
+    fn it_encodes_names() {
+        let code = RoomCode::encode("very encrypted name", 343).unwrap();
+        assert_eq!("qzmt-zixmtkozy-ivhz-343[zimth]", code.to_string());
+        assert!(code.is_valid());
+    }
+
+    #[test]
+    fn it_rejects_names_it_cannot_encode() {
+        let expected = |column, token: &str| Err(ParseError::new(column, token, "a lowercase letter"));
+        assert_eq!(expected(7, "N"), RoomCode::encode("north Nole", 1).map(|c| c.to_string()));
+        assert_eq!(expected(7, " "), RoomCode::encode("north  pole", 1).map(|c| c.to_string()));
+        assert_eq!(expected(1, " "), RoomCode::encode(" northpole", 1).map(|c| c.to_string()));
+        assert_eq!(expected(10, " "), RoomCode::encode("northpole ", 1).map(|c| c.to_string()));
+        assert_eq!(expected(1, ""), RoomCode::encode("", 1).map(|c| c.to_string()));
+        assert_eq!(expected(3, "é"), RoomCode::encode("abécde", 1).map(|c| c.to_string()));
+        let error = RoomCode::encode("aab ccd", 1).unwrap_err();
+        assert_eq!(ParseError::new(1, "aab ccd", "a name with at least 5 different letters"), error);
+    }
+
+    #[test]
+    fn it_round_trips_pseudo_random_names() {
+        let mut seed: u32 = 4242;
+        let mut next = |bound: u32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (seed >> 8) % bound
+        };
+        for _ in 0..500 {
+            let words = (0..1 + next(4))
+                .map(|_| (0..1 + next(10)).map(|_| (b'a' + next(26) as u8) as char).collect::<String>())
+                .collect::<Vec<_>>();
+            let name = words.join(" ");
+            let sector_id = next(u32::MAX);
+            match RoomCode::encode(&name, sector_id) {
+                Ok(code) => {
+                    let parsed: RoomCode = code.to_string().parse().unwrap();
+                    assert!(parsed.is_valid(), "{}", code);
+                    assert_eq!(sector_id, parsed.sector_id);
+                    assert_eq!(name, parsed.decrypted_name());
+                },
+                Err(error) => {
+                    let mut letters = name.replace(' ', "").into_bytes();
+                    letters.sort_unstable();
+                    letters.dedup();
+                    assert!(letters.len() < 5, "{}: {}", name, error);
+                },
+            }
+        }
+    }
+
+}
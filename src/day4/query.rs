@@ -0,0 +1,110 @@
+use regex::{self, Regex};
+use std::fmt::Write;
+
+use day4::RoomCode;
+
+// How a query is compared with decrypted room names.
+#[derive(Debug, Clone)]
+pub enum Matcher {
+    Exact(String),
+    Substring(String),
+    Pattern(Regex),
+}
+
+impl Matcher {
+    pub fn exact(name: &str) -> Matcher {
+        Matcher::Exact(name.to_string())
+    }
+
+    pub fn substring(text: &str) -> Matcher {
+        Matcher::Substring(text.to_string())
+    }
+
+    pub fn pattern(pattern: &str) -> Result<Matcher, regex::Error> {
+        Regex::new(pattern).map(Matcher::Pattern)
+    }
+
+    pub fn matches(&self, name: &str) -> bool {
+        match *self {
+            Matcher::Exact(ref exact) => name == exact,
+            Matcher::Substring(ref text) => name.contains(text.as_str()),
+            Matcher::Pattern(ref regex) => regex.is_match(name),
+        }
+    }
+}
+
+// The sector id and decrypted name of every valid room that matches, in
+// input order. Decoys are never matched.
+pub fn search(codes: &[RoomCode], matcher: &Matcher) -> Vec<(u32, String)> {
+    codes.iter()
+        .filter(|code| code.is_valid())
+        .map(|code| (code.sector_id, code.decrypted_name()))
+        .filter(|room| matcher.matches(&room.1))
+        .collect()
+}
+
+pub fn json(rooms: &[(u32, String)]) -> String {
+    let mut json = String::from("[");
+    for (index, &(sector_id, ref name)) in rooms.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        write!(json, "\n  {{\"sector_id\": {}, \"name\": ", sector_id).unwrap();
+        json_string(&mut json, name);
+        json.push('}');
+    }
+    json.push_str(if rooms.is_empty() { "]\n" } else { "\n]\n" });
+    json
+}
+
+fn json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parse::parse_lines;
+
+    fn codes() -> Vec<RoomCode> {
+        parse_lines("qzmt-zixmtkozy-ivhz-343[zimth]
+                     ghkmaihex-hucxvm-lmhktzx-267[hmxka]
+                     totally-real-room-200[decoy]
+                     aaaaa-bbb-z-y-x-123[abxyz]").unwrap()
+    }
+
+    #[test]
+    fn it_matches_names() {
+        assert!(Matcher::exact("northpole object").matches("northpole object"));
+        assert!(!Matcher::exact("northpole").matches("northpole object"));
+        assert!(Matcher::substring("pole").matches("northpole object"));
+        assert!(Matcher::pattern("^north.*t$").unwrap().matches("northpole object"));
+        assert!(Matcher::pattern("(").is_err());
+    }
+
+    #[test]
+    fn it_searches_valid_rooms() {
+        let codes = codes();
+        assert_eq!(vec![(343, "very encrypted name".to_string())], search(&codes, &Matcher::substring("crypt")));
+        assert_eq!(vec![(267, "northpole object storage".to_string())], search(&codes, &Matcher::pattern("^north").unwrap()));
+        assert_eq!(3, search(&codes, &Matcher::pattern("").unwrap()).len());
+        assert!(search(&codes, &Matcher::substring("real")).is_empty());
+    }
+
+    #[test]
+    fn it_writes_json() {
+        let rooms = vec![(343, "very encrypted name".to_string()), (1, "say \"hi\"\\".to_string())];
+        assert_eq!("[\n  {\"sector_id\": 343, \"name\": \"very encrypted name\"},\n  {\"sector_id\": 1, \"name\": \"say \\\"hi\\\"\\\\\"}\n]\n", json(&rooms));
+        assert_eq!("[]\n", json(&[]));
+    }
+}
@@ -0,0 +1,38 @@
+use parse::{ParseError, Tokens};
+use solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tokens = Tokens::new(input);
+        let door_id = tokens.next_token("a door id")?.text.to_string();
+        tokens.finish()?;
+        Ok(door_id)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(format!("{:08x}", part1::generate(input)))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(format!("{:08x}", part2::generate(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reports_malformed_door_ids() {
+        assert_eq!(Ok("abc".to_string()), Day5::parse("  abc\n"));
+        assert_eq!(ParseError::new(1, "", "a door id"), Day5::parse("").unwrap_err());
+        assert_eq!(ParseError::new(5, "def", "end of line"), Day5::parse("abc def").unwrap_err());
+    }
+}
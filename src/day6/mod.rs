@@ -0,0 +1,72 @@
+use parse::ParseError;
+use solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+// Each message is eight characters long, one per column counted.
+pub const MESSAGE_LENGTH: usize = 8;
+
+pub fn validate(input: &str) -> Result<(), ParseError> {
+    let mut messages = 0;
+    for (index, line) in input.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+        let message = trimmed.trim_end();
+        let length = message.chars().count();
+        let error = if let Some((column, c)) = message.char_indices().find(|&(_, c)| c.is_whitespace()) {
+            Some(ParseError::new(column + 1, &c.to_string(), "a letter"))
+        } else if length < MESSAGE_LENGTH {
+            Some(ParseError::new(message.len() + 1, "", "8 letters"))
+        } else if length > MESSAGE_LENGTH {
+            let (column, _) = message.char_indices().nth(MESSAGE_LENGTH).unwrap();
+            Some(ParseError::new(column + 1, &message[column..], "end of line"))
+        } else {
+            None
+        };
+        if let Some(error) = error {
+            return Err(error.shifted(indent).on_line(index + 1));
+        }
+        messages += 1;
+    }
+    if messages == 0 {
+        return Err(ParseError::new(1, "", "a message"));
+    }
+    Ok(())
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        validate(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(part1::rebuild_message(input.lines()))
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(part2::rebuild_message(input.lines()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reports_malformed_messages() {
+        assert!(Day6::parse("eedadnzz\n\n  drvteezz \n").is_ok());
+        assert_eq!("line 2, column 6: expected 8 letters, found end of line", Day6::parse("eedadnzz\ndrvte").unwrap_err().to_string());
+        assert_eq!(ParseError::new(9, "zy", "end of line"), Day6::parse("eedadnzzzy").unwrap_err());
+        assert_eq!(ParseError::new(4, " ", "a letter"), Day6::parse("eed adnzz").unwrap_err());
+        assert_eq!(ParseError::new(1, "", "a message"), Day6::parse("\n").unwrap_err());
+    }
+}
@@ -0,0 +1,67 @@
+use parse::ParseError;
+use solution::{Answer, Solution};
+
+pub mod part1;
+pub mod part2;
+
+pub fn validate(s: &str) -> Result<(), ParseError> {
+    let mut open = None;
+    for (index, c) in s.char_indices() {
+        let token = &s[index..index + c.len_utf8()];
+        match c {
+            'a'..='z' => (),
+            '[' if open.is_none() => open = Some(index),
+            '[' => return Err(ParseError::new(index + 1, token, "a closing `]`")),
+            ']' if open.is_some() => open = None,
+            ']' => return Err(ParseError::new(index + 1, token, "an opening `[` first")),
+            _ => return Err(ParseError::new(index + 1, token, "a lowercase letter")),
+        }
+    }
+    match open {
+        Some(_) => Err(ParseError::new(s.len() + 1, "", "a closing `]`")),
+        None => Ok(()),
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = vec![];
+        for (index, line) in input.lines().enumerate() {
+            validate(line).map_err(|e| e.on_line(index + 1))?;
+            lines.push(line.to_string());
+        }
+        Ok(lines)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(part1::IPv7::sum_valid(input.iter().map(|x| x.as_str()).collect())?.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(part2::IPv7::sum_valid(input.iter().map(|x| x.as_str()).collect())?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_validates_addresses() {
+        assert_eq!(Ok(()), validate("abba[mnop]qrst"));
+        assert_eq!(Err(ParseError::new(5, "1", "a lowercase letter")), validate("abba1"));
+        assert_eq!(Err(ParseError::new(4, "[", "a closing `]`")), validate("a[b[c]]"));
+        assert_eq!(Err(ParseError::new(2, "]", "an opening `[` first")), validate("a]"));
+        assert_eq!(Err(ParseError::new(7, "", "a closing `]`")), validate("abba[m"));
+    }
+
+    #[test]
+    fn it_reports_the_bad_line() {
+        let error = Day7::parse("abba[mnop]qrst\nab[ba").unwrap_err();
+        assert_eq!(2, error.line);
+    }
+}
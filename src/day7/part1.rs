@@ -0,0 +1,136 @@
+use regex::Regex;
+use std::str::FromStr;
+use day7::validate;
+use parse::ParseError;
+
+lazy_static! {
+    static ref HYPERNET: Regex = Regex::new(r#"\[(\w+)\]"#).unwrap();
+}
+
+#[derive(Debug)]
+pub struct IPv7 {
+    hypernet_sequences: Vec<String>,
+    address: String,
+}
+
+impl IPv7 {
+    pub fn supports_tls(&self) -> bool {
+        for sequence in self.hypernet_sequences.iter() {
+            if has_abba(sequence) {
+                return false;
+            }
+        }
+        has_abba(&self.address)
+    }
+
+    pub fn sum_valid(ips: Vec<&str>) -> Result<u32, ParseError> {
+        let mut sum = 0;
+        for (index, ip) in ips.iter().enumerate() {
+            let parsed: IPv7 = ip.parse().map_err(|e: ParseError| e.on_line(index + 1))?;
+            if parsed.supports_tls() {
+                sum += 1;
+            }
+        }
+        Ok(sum)
+    }
+}
+
+impl FromStr for IPv7 {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate(s)?;
+        let mut captures = vec![];
+        for capture in HYPERNET.captures_iter(s) {
+            captures.push(capture.at(1).unwrap_or("").to_string());
+        }
+        Ok(IPv7{
+            hypernet_sequences: captures,
+            address: s.to_string(),
+        })
+    }
+}
+
+pub fn has_abba(input: &str) -> bool {
+    let raw = input.bytes().collect::<Vec<u8>>();
+    for i in 3..raw.len() {
+        if raw[i - 3] == raw[i - 2] {
+            continue;
+        }
+        if raw[i - 3] == raw[i] && raw[i - 2] == raw[i - 1] {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_detects_abbas() {
+        assert!(has_abba("abba"));
+        assert!(has_abba("xabba"));
+        assert!(has_abba("xyyx"));
+        assert!(has_abba("ioxxoj"));
+    }
+
+    #[test]
+    fn it_doesnt_detect_no_abbas() {
+        assert!(!has_abba("aaaa"));
+        assert!(!has_abba("abcd"));
+    }
+
+    #[test]
+    fn it_parses_ipv7() {
+        let _: IPv7 = "abba[mnop]qrst".parse().unwrap();
+    }
+
+    #[test]
+    fn it_rejects_unclosed_hypernets() {
+        assert!("abba[mnop".parse::<IPv7>().is_err());
+    }
+
+    #[test]
+    fn it_extracts_hypernet_sequences() {
+        let ipv7: IPv7 = "onmmhtsykubbpdiqvjm[kbfbiyjyuzmemaomkwa]prqwqocsihfnslooel[hysggeprqecalydywlk]taghiwhgnujsduhnffu[ibpvowghgttfsvt]wcajwcxhcriflxi".parse().unwrap();
+        assert_eq!(vec!["kbfbiyjyuzmemaomkwa", "hysggeprqecalydywlk", "ibpvowghgttfsvt"], ipv7.hypernet_sequences);
+    }
+
+    #[test]
+    fn it_extracts_address() {
+        let ipv7: IPv7 = "abba[mnop]qrst".parse().unwrap();
+        assert_eq!("abba[mnop]qrst", ipv7.address);
+    }
+
+    #[test]
+    fn it_knows_when_ipv7_supports_tls() {
+        let ipv7: IPv7 = "abba[mnop]qrst".parse().unwrap();
+        assert!(ipv7.supports_tls());
+
+        let ipv7_2: IPv7 = "ioxxoj[asdfgh]zxcvbn".parse().unwrap();
+        assert!(ipv7_2.supports_tls());
+    }
+
+    #[test]
+    fn it_knows_when_ipv7_doesnt_support_tls() {
+        let ipv7_1: IPv7 = "abcd[bddb]xyyx".parse().unwrap();
+        assert!(!ipv7_1.supports_tls());
+
+        let ipv7_2: IPv7 = "aaaa[qwer]tyui".parse().unwrap();
+        assert!(!ipv7_2.supports_tls());
+    }
+
+    #[test]
+    fn it_sums_valid() {
+        let ips = vec![
+            "abba[mnop]qrst",
+            "abcd[bddb]xyyx",
+            "aaaa[qwer]tyui",
+            "ioxxoj[asdfgh]zxcvbn",
+        ];
+        assert_eq!(Ok(2), IPv7::sum_valid(ips));
+        let error = IPv7::sum_valid(vec!["abba", "ab[ba"]).unwrap_err();
+        assert_eq!(ParseError::new(6, "", "a closing `]`").on_line(2), error);
+    }
+}
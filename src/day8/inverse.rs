@@ -0,0 +1,213 @@
+use std::fmt;
+
+use day8::Display;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Step {
+    Rect(usize, usize),
+    RotateRow(usize, usize),
+    RotateColumn(usize, usize),
+}
+
+impl Step {
+    // The same step on a screen with rows and columns swapped.
+    pub fn transposed(&self) -> Step {
+        match *self {
+            Step::Rect(width, height) => Step::Rect(height, width),
+            Step::RotateRow(y, by) => Step::RotateColumn(y, by),
+            Step::RotateColumn(x, by) => Step::RotateRow(x, by),
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Step::Rect(width, height) => write!(f, "rect {}x{}", width, height),
+            Step::RotateRow(y, by) => write!(f, "rotate row y={} by {}", y, by),
+            Step::RotateColumn(x, by) => write!(f, "rotate column x={} by {}", x, by),
+        }
+    }
+}
+
+// Builds the target column by column, and again row by row on the transposed
+// screen, and keeps the shortest program that replays to the target.
+pub fn steps(target: &Display) -> Vec<Step> {
+    let rows = by_columns(&transpose(target)).iter()
+        .map(Step::transposed)
+        .collect::<Vec<_>>();
+    let candidates = vec![solid_rect(target).map(|rect| vec![rect]), Some(by_columns(target)), Some(rows)];
+    candidates.into_iter()
+        .flatten()
+        .filter(|steps| replay(steps, target.width(), target.height()) == *target)
+        .min_by_key(|steps| steps.len())
+        .expect("drawing column by column always reproduces the target")
+}
+
+pub fn replay(steps: &[Step], width: usize, height: usize) -> Display {
+    let mut display = Display::with_size(width, height);
+    for step in steps {
+        match *step {
+            Step::Rect(width, height) => display.rect(width, height),
+            Step::RotateRow(y, by) => display.rotate_row(y, by),
+            Step::RotateColumn(x, by) => display.rotate_column(x, by),
+        }
+    }
+    display
+}
+
+pub fn instructions(target: &Display) -> Vec<String> {
+    steps(target).iter().map(|step| step.to_string()).collect()
+}
+
+// Draws one column at a time, from the right. Each column is drawn into
+// column 0 a run of pixels at a time (a rect, then a column rotation to slide
+// the runs down), and every row that gained a pixel is then rotated right by
+// the gap to its next pixel to the left. That empties column 0 again, and
+// leaves each row holding its finished pixels shifted left by exactly the
+// column its next pixel belongs in.
+fn by_columns(target: &Display) -> Vec<Step> {
+    let (width, height) = (target.width(), target.height());
+    let mut steps = vec![];
+    for x in (0..width).rev() {
+        let lit = (0..height).filter(|&y| target.get(x, y)).collect::<Vec<_>>();
+        if lit.is_empty() {
+            continue;
+        }
+
+        let runs = runs(&lit);
+        for (index, &(start, len)) in runs.iter().enumerate().rev() {
+            steps.push(Step::Rect(1, len));
+            let above = if index == 0 { 0 } else { runs[index - 1].0 };
+            if start > above {
+                steps.push(Step::RotateColumn(0, start - above));
+            }
+        }
+
+        for y in lit {
+            let next = (0..x).rev().find(|&left| target.get(left, y)).unwrap_or(0);
+            if x > next {
+                steps.push(Step::RotateRow(y, x - next));
+            }
+        }
+    }
+    steps
+}
+
+// Groups sorted rows into (start, length) runs of neighbouring rows.
+fn runs(rows: &[usize]) -> Vec<(usize, usize)> {
+    let mut runs: Vec<(usize, usize)> = vec![];
+    for &row in rows {
+        match runs.last_mut() {
+            Some(&mut (start, ref mut len)) if start + *len == row => *len += 1,
+            _ => runs.push((row, 1)),
+        }
+    }
+    runs
+}
+
+// A target that is just a lit rectangle in the top left corner is one rect:
+// every pixel inside the box is on and every pixel outside it is off.
+fn solid_rect(target: &Display) -> Option<Step> {
+    let width = (0..target.width()).take_while(|&x| target.get(x, 0)).count();
+    let height = (0..target.height()).take_while(|&y| target.get(0, y)).count();
+    if width == 0 {
+        return None;
+    }
+    for y in 0..target.height() {
+        for x in 0..target.width() {
+            if target.get(x, y) != (x < width && y < height) {
+                return None;
+            }
+        }
+    }
+    Some(Step::Rect(width, height))
+}
+
+fn transpose(display: &Display) -> Display {
+    let mut transposed = Display::with_size(display.height(), display.width());
+    for y in 0..display.height() {
+        for x in 0..display.width() {
+            transposed.set(y, x, display.get(x, y));
+        }
+    }
+    transposed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(target: &Display) -> Display {
+        let mut display = Display::with_size(target.width(), target.height());
+        for instruction in instructions(target) {
+            display.parse(&instruction).unwrap();
+        }
+        display
+    }
+
+    #[test]
+    fn it_groups_runs() {
+        assert_eq!(vec![(0, 2), (3, 1), (5, 1)], runs(&[0, 1, 3, 5]));
+    }
+
+    #[test]
+    fn it_draws_nothing_for_a_blank_screen() {
+        assert!(instructions(&Display::new()).is_empty());
+    }
+
+    #[test]
+    fn it_reproduces_the_example() {
+        let target = Display::from_art(".#..#.#\n#.#....\n.#.....").unwrap();
+        assert_eq!(target, draw(&target));
+    }
+
+    #[test]
+    fn it_reproduces_letters() {
+        let target = Display::from_art(".##..###..#...#.....####.\n\
+                                        #..#.#..#.#...#........#.\n\
+                                        #..#.###...#.#........#..\n\
+                                        ####.#..#...#........#...\n\
+                                        #..#.#..#...#.......#....\n\
+                                        #..#.###....#.......####.").unwrap();
+        assert_eq!(target, draw(&target));
+        assert_eq!(Ok("ABY Z".to_string()), draw(&target).read_text());
+    }
+
+    #[test]
+    fn it_reproduces_pseudo_random_screens() {
+        let mut seed: u32 = 12345;
+        for _ in 0..50 {
+            let mut target = Display::new();
+            for y in 0..target.height() {
+                for x in 0..target.width() {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                    if (seed >> 29) < 3 {
+                        target.set(x, y, true);
+                    }
+                }
+            }
+            assert_eq!(target.print(), draw(&target).print());
+        }
+    }
+
+    #[test]
+    fn it_draws_solid_rects_in_one_step() {
+        let target = Display::from_art("###.\n###.\n....").unwrap();
+        assert_eq!(vec!["rect 3x2"], instructions(&target));
+    }
+
+    #[test]
+    fn it_only_takes_boxes_that_are_filled_in_as_rects() {
+        let target = Display::from_art("##..\n#...\n....\n...#").unwrap();
+        assert_eq!(None, solid_rect(&target));
+        assert_eq!(target, draw(&target));
+        assert_eq!(target, replay(&steps(&target), 4, 4));
+    }
+
+    #[test]
+    fn it_builds_rows_when_that_is_shorter() {
+        let target = Display::from_art("##.##.\n......").unwrap();
+        assert_eq!(vec!["rect 2x1", "rotate row y=0 by 3", "rect 2x1"], instructions(&target));
+    }
+}
@@ -0,0 +1,400 @@
+use regex::{Captures, Regex};
+use parse::ParseError;
+use solution::{Answer, Solution};
+
+pub mod inverse;
+pub mod ocr;
+pub mod recorder;
+
+lazy_static! {
+    static ref RECT: Regex = Regex::new(r#"^rect (\d+)x(\d+)$"#).unwrap();
+    static ref ROTATE_COLUMN: Regex = Regex::new(r#"^rotate column x=(\d+) by (\d+)$"#).unwrap();
+    static ref ROTATE_ROW: Regex = Regex::new(r#"^rotate row y=(\d+) by (\d+)$"#).unwrap();
+}
+
+pub const WIDTH: usize = 50;
+pub const HEIGHT: usize = 6;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Display {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display {
+    pub fn new() -> Display {
+        Display::with_size(WIDTH, HEIGHT)
+    }
+
+    pub fn with_size(width: usize, height: usize) -> Display {
+        Display {
+            width,
+            height,
+            pixels: vec![false; width * height],
+        }
+    }
+
+    // Reads `#`/`.` pixel art in the format `print` writes.
+    pub fn from_art(art: &str) -> Result<Display, ParseError> {
+        let rows = art.lines().map(|row| row.trim()).collect::<Vec<_>>();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut display = Display::with_size(width, rows.len());
+        for (y, row) in rows.iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::new(1, row, &format!("{} pixels", width)).on_line(y + 1));
+            }
+            for (x, pixel) in row.chars().enumerate() {
+                match pixel {
+                    '#' => display.set(x, y, true),
+                    '.' => (),
+                    _ => return Err(ParseError::new(x + 1, &pixel.to_string(), "`#` or `.`").on_line(y + 1)),
+                }
+            }
+        }
+        Ok(display)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * self.width + x]
+    }
+
+    fn set(&mut self, x: usize, y: usize, pixel: bool) {
+        self.pixels[y * self.width + x] = pixel;
+    }
+
+    pub fn on_pixels(&self) -> u32 {
+        self.pixels.iter().filter(|pixel| **pixel).count() as u32
+    }
+
+    pub fn print(&self) -> String {
+        let mut display = vec![];
+        for row in self.pixels.chunks(self.width.max(1)) {
+            let display_row = row.iter()
+                .map(|pixel| if *pixel { '#' } else { '.' })
+                .collect::<String>();
+            display.push(display_row);
+        }
+        display.join("\n")
+    }
+
+    pub fn read_text(&self) -> Result<String, ocr::UnknownGlyphs> {
+        ocr::read_text(self)
+    }
+
+    // These trust their arguments, which `parse` has already bounds-checked.
+    fn rect(&mut self, width: usize, height: usize) {
+        for y in 0..height {
+            for x in 0..width {
+                self.set(x, y, true);
+            }
+        }
+    }
+
+    fn rotate_row(&mut self, row_index: usize, rotation: usize) {
+        let start = row_index * self.width;
+        let row: Vec<bool> = self.pixels[start..start + self.width].to_vec();
+        let shifted: Vec<bool> = rotate(row, rotation);
+        for (index, pixel) in shifted.iter().enumerate() {
+            self.set(index, row_index, *pixel);
+        }
+    }
+
+    fn rotate_column(&mut self, column_index: usize, rotation: usize) {
+        let mut column: Vec<bool> = vec![];
+        for y in 0..self.height {
+            column.push(self.get(column_index, y));
+        }
+        let shifted: Vec<bool> = rotate(column, rotation);
+        for (index, pixel) in shifted.iter().enumerate() {
+            self.set(column_index, index, *pixel);
+        }
+    }
+
+    pub fn parse(&mut self, raw_instruction: &str) -> Result<(), ParseError> {
+        if let Some(matches) = RECT.captures(raw_instruction) {
+            let x = number(raw_instruction, &matches, 1, self.width + 1, "a width")?;
+            let y = number(raw_instruction, &matches, 2, self.height + 1, "a height")?;
+            self.rect(x, y);
+            return Ok(());
+        }
+
+        if let Some(matches) = ROTATE_COLUMN.captures(raw_instruction) {
+            let column = number(raw_instruction, &matches, 1, self.width, "a column")?;
+            let rotation = number(raw_instruction, &matches, 2, usize::MAX, "a rotation")?;
+            self.rotate_column(column, rotation);
+            return Ok(());
+        }
+
+        if let Some(matches) = ROTATE_ROW.captures(raw_instruction) {
+            let row = number(raw_instruction, &matches, 1, self.height, "a row")?;
+            let rotation = number(raw_instruction, &matches, 2, usize::MAX, "a rotation")?;
+            self.rotate_row(row, rotation);
+            return Ok(());
+        }
+
+        Err(ParseError::new(1, raw_instruction, "rect, rotate row or rotate column"))
+    }
+}
+
+// Reads capture `index` as a number below `limit`, pointing any error at
+// the number itself.
+fn number(raw_instruction: &str, matches: &Captures, index: usize, limit: usize, expected: &str) -> Result<usize, ParseError> {
+    let (start, end) = matches.pos(index).unwrap();
+    let text = &raw_instruction[start..end];
+    match text.parse() {
+        Ok(value) if value < limit => Ok(value),
+        Ok(_) => Err(ParseError::new(start + 1, text, &format!("{} below {}", expected, limit))),
+        Err(_) => Err(ParseError::new(start + 1, text, expected)),
+    }
+}
+
+pub fn rotate(mut row: Vec<bool>, rotation: usize) -> Vec<bool> {
+    let len = row.len();
+    if len == 0 {
+        return row;
+    }
+    let rotation = rotation % len;
+    let mut shifted: Vec<bool> = row.drain((len - rotation)..len).collect();
+    shifted.append(&mut row);
+    shifted
+}
+
+// Calls `apply` on every non-blank line, placing any error at its line and
+// column in `input`.
+pub fn each_instruction<F>(input: &str, mut apply: F) -> Result<(), ParseError>
+    where F: FnMut(&str) -> Result<(), ParseError>
+{
+    for (index, line) in input.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+        apply(trimmed.trim_end()).map_err(|e| e.shifted(indent).on_line(index + 1))?;
+    }
+    Ok(())
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut display = Display::new();
+        each_instruction(input, |instruction| display.parse(instruction))?;
+        Ok(display)
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(input.on_pixels().to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        // Falls back to the pixel art when the font doesn't cover a letter.
+        Ok(input.read_text().unwrap_or_else(|_| input.print()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_creates_a_display() {
+        let _: Display = Display::new();
+    }
+
+    #[test]
+    fn it_counts_on_pixels() {
+        let display = Display::new();
+        assert_eq!(0, display.on_pixels());
+    }
+
+    #[test]
+    fn it_prints_display() {
+        let output = "..................................................\n\
+                      ..................................................\n\
+                      ..................................................\n\
+                      ..................................................\n\
+                      ..................................................\n\
+                      ..................................................";
+        let display = Display::new();
+        assert_eq!(output, display.print());
+    }
+
+    #[test]
+    fn it_draws_rect() {
+        let mut display = Display::new();
+        display.rect(3, 2);
+
+        let expected = "###...............................................\n\
+                        ###...............................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................";
+        println!("\nexpected:\n{}", expected);
+
+        let actual = display.print();
+
+        println!("actual:\n{}", actual);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn it_rotates_row() {
+        let mut display = Display::new();
+        display.rect(3, 2);
+        display.rotate_row(0, 4);
+
+        let expected = "....###...........................................\n\
+                        ###...............................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................";
+        println!("\nexpected:\n{}", expected);
+
+        let actual = display.print();
+
+        println!("actual:\n{}", actual);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn it_rotates_column() {
+        let mut display = Display::new();
+        display.rect(3, 2);
+        display.rotate_column(1, 2);
+
+        let expected = "#.#...............................................\n\
+                        #.#...............................................\n\
+                        .#................................................\n\
+                        .#................................................\n\
+                        ..................................................\n\
+                        ..................................................";
+        println!("\nexpected:\n{}", expected);
+
+        let actual = display.print();
+
+        println!("actual:\n{}", actual);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn it_parses_rect_instruction() {
+        let mut display = Display::new();
+        display.parse("rect 3x2").unwrap();
+
+        let expected = "###...............................................\n\
+                        ###...............................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................";
+        println!("\nexpected:\n{}", expected);
+
+        let actual = display.print();
+
+        println!("actual:\n{}", actual);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn it_parses_rotate_column() {
+        let mut display = Display::new();
+        display.rect(3,2);
+        display.parse("rotate column x=1 by 1").unwrap();
+
+        let expected = "#.#...............................................\n\
+                        ###...............................................\n\
+                        .#................................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................";
+        println!("\nexpected:\n{}", expected);
+
+        let actual = display.print();
+
+        println!("actual:\n{}", actual);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn it_parses_rotates_row() {
+        let mut display = Display::new();
+        display.rect(3, 2);
+        display.parse("rotate row y=0 by 4").unwrap();
+
+        let expected = "....###...........................................\n\
+                        ###...............................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................\n\
+                        ..................................................";
+        println!("\nexpected:\n{}", expected);
+
+        let actual = display.print();
+
+        println!("actual:\n{}", actual);
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn it_reads_pixel_art() {
+        let display = Display::from_art("#..\n.##").unwrap();
+        assert_eq!((3, 2), (display.width(), display.height()));
+        assert_eq!("#..\n.##", display.print());
+        assert_eq!("line 2, column 2: expected `#` or `.`, found `x`", Display::from_art("#..\n.x#").unwrap_err().to_string());
+        assert_eq!(2, Display::from_art("#..\n.#").unwrap_err().line);
+    }
+
+    #[test]
+    fn it_draws_on_a_small_display() {
+        let mut display = Display::with_size(7, 3);
+        for instruction in &["rect 3x2", "rotate column x=1 by 1", "rotate row y=0 by 4", "rotate column x=1 by 1"] {
+            display.parse(instruction).unwrap();
+        }
+        assert_eq!(".#..#.#\n#.#....\n.#.....", display.print());
+        assert_eq!(6, display.on_pixels());
+    }
+
+    #[test]
+    fn it_wraps_long_rotations() {
+        let mut display = Display::with_size(7, 3);
+        display.parse("rect 1x1").unwrap();
+        display.parse("rotate row y=0 by 15").unwrap();
+        assert!(display.get(1, 0));
+    }
+
+    #[test]
+    fn it_rejects_out_of_range_instructions() {
+        let mut display = Display::with_size(7, 3);
+        assert_eq!(ParseError::new(8, "4", "a height below 4"), display.parse("rect 7x4").unwrap_err());
+        assert_eq!(ParseError::new(17, "7", "a column below 7"), display.parse("rotate column x=7 by 1").unwrap_err());
+        assert_eq!(ParseError::new(14, "3", "a row below 3"), display.parse("rotate row y=3 by 1").unwrap_err());
+        assert_eq!(0, display.on_pixels());
+    }
+
+    #[test]
+    fn it_rejects_unknown_instructions() {
+        let error = Day8::parse("rect 1x1\n  flip x=1").err().unwrap();
+        assert_eq!("line 2, column 3: expected rect, rotate row or rotate column, found `flip x=1`", error.to_string());
+    }
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+
+use day8::Display;
+
+pub const GLYPH_WIDTH: usize = 5;
+pub const GLYPH_HEIGHT: usize = 6;
+
+// Letters are four columns wide with a blank fifth column between them,
+// except for Y which uses all five.
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 19] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+    (' ', ["....", "....", "....", "....", "....", "...."]),
+];
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnknownGlyphs {
+    pub glyphs: Vec<(usize, Vec<String>)>,
+}
+
+impl fmt::Display for UnknownGlyphs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, &(column, ref rows)) in self.glyphs.iter().enumerate() {
+            if index != 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "unknown glyph at column {}:", column)?;
+            for row in rows {
+                writeln!(f, "{}", row)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// The glyph starting at `column`, padded with dark pixels past the right
+// edge of the display.
+pub fn glyph(display: &Display, column: usize) -> Vec<String> {
+    (0..display.height())
+        .map(|y| {
+            (column..column + GLYPH_WIDTH)
+                .map(|x| if x < display.width() && display.get(x, y) { '#' } else { '.' })
+                .collect()
+        })
+        .collect()
+}
+
+pub fn recognize(rows: &[String]) -> Option<char> {
+    if rows.len() != GLYPH_HEIGHT {
+        return None;
+    }
+    FONT.iter()
+        .find(|&(_, art)| {
+            art.iter().zip(rows).all(|(expected, row)| {
+                row.starts_with(expected) && row[expected.len()..].chars().all(|c| c == '.')
+            })
+        })
+        .map(|&(letter, _)| letter)
+}
+
+pub fn read_text(display: &Display) -> Result<String, UnknownGlyphs> {
+    let mut text = String::new();
+    let mut unknown = vec![];
+    for column in (0..display.width()).step_by(GLYPH_WIDTH) {
+        let rows = glyph(display, column);
+        match recognize(&rows) {
+            Some(letter) => text.push(letter),
+            None => unknown.push((column, rows)),
+        }
+    }
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(UnknownGlyphs { glyphs: unknown })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn display(art: &str) -> Display {
+        Display::from_art(art).unwrap()
+    }
+
+    #[test]
+    fn it_reads_letters() {
+        let display = display(".##..###..#...#.....####.\n\
+                               #..#.#..#.#...#........#.\n\
+                               #..#.###...#.#........#..\n\
+                               ####.#..#...#........#...\n\
+                               #..#.#..#...#.......#....\n\
+                               #..#.###....#.......####.");
+        assert_eq!(Ok("ABY Z".to_string()), read_text(&display));
+    }
+
+    #[test]
+    fn it_reads_a_blank_screen_as_spaces() {
+        assert_eq!(Ok(" ".repeat(10)), read_text(&Display::new()));
+    }
+
+    #[test]
+    fn it_lists_unknown_glyphs() {
+        let display = display(".##..##...\n\
+                               #..#.##...\n\
+                               #..#......\n\
+                               ####......\n\
+                               #..#......\n\
+                               #..#......");
+        let error = read_text(&display).unwrap_err();
+        assert_eq!(1, error.glyphs.len());
+        assert_eq!(5, error.glyphs[0].0);
+        assert_eq!("unknown glyph at column 5:\n##...\n##...\n.....\n.....\n.....\n.....\n", error.to_string());
+    }
+
+    #[test]
+    fn it_rejects_displays_of_the_wrong_height() {
+        assert!(read_text(&Display::with_size(5, 5)).is_err());
+    }
+}
@@ -0,0 +1,202 @@
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use day8::{each_instruction, Display};
+use parse::ParseError;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Frame {
+    pub instruction: Option<String>,
+    pub display: Display,
+}
+
+// Keeps a copy of the display after every instruction, starting with the
+// blank screen.
+pub struct Recorder {
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new(display: Display) -> Recorder {
+        Recorder {
+            frames: vec![Frame { instruction: None, display }],
+        }
+    }
+
+    pub fn record(display: Display, input: &str) -> Result<Recorder, ParseError> {
+        let mut recorder = Recorder::new(display);
+        each_instruction(input, |instruction| recorder.apply(instruction))?;
+        Ok(recorder)
+    }
+
+    pub fn apply(&mut self, raw_instruction: &str) -> Result<(), ParseError> {
+        let mut display = self.display().clone();
+        display.parse(raw_instruction)?;
+        self.frames.push(Frame {
+            instruction: Some(raw_instruction.to_string()),
+            display,
+        });
+        Ok(())
+    }
+
+    pub fn display(&self) -> &Display {
+        &self.frames[self.frames.len() - 1].display
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn ansi_frames(&self) -> Vec<String> {
+        let total = self.frames.len() - 1;
+        self.frames.iter()
+            .enumerate()
+            .map(|(index, frame)| {
+                let caption = frame.instruction.as_ref().map_or("start", |s| s.as_str());
+                format!("\x1b[H\x1b[2J{}/{} {}\n{}\n", index, total, caption, ansi(&frame.display))
+            })
+            .collect()
+    }
+
+    pub fn play<W: Write>(&self, out: &mut W, delay: Duration) -> io::Result<()> {
+        for frame in self.ansi_frames() {
+            out.write_all(frame.as_bytes())?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+
+    // Writes frame_000.pbm, frame_001.pbm, ... (or .pgm) into `directory`.
+    pub fn write_images(&self, directory: &Path, format: Format) -> io::Result<()> {
+        for index in 0..self.frames.len() {
+            let name = format!("frame_{:03}.{}", index, format.extension());
+            let mut file = File::create(directory.join(name))?;
+            let image = match format {
+                Format::Pbm => pbm(&self.frames[index].display),
+                Format::Pgm => self.pgm(index),
+            };
+            file.write_all(image.as_bytes())?;
+        }
+        Ok(())
+    }
+
+    // Greyscale frame, dark on light like the PBM export, where pixels the
+    // instruction switched on or off are grey so each step stands out.
+    pub fn pgm(&self, index: usize) -> String {
+        let display = &self.frames[index].display;
+        let previous = &self.frames[index.saturating_sub(1)].display;
+        let mut image = format!("P2\n{} {}\n255\n", display.width(), display.height());
+        for y in 0..display.height() {
+            let row = (0..display.width())
+                .map(|x| match (previous.get(x, y), display.get(x, y)) {
+                    (false, false) => "255",
+                    (true, false) => "170",
+                    (false, true) => "85",
+                    (true, true) => "0",
+                })
+                .collect::<Vec<_>>();
+            image.push_str(&row.join(" "));
+            image.push('\n');
+        }
+        image
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Format {
+    Pbm,
+    Pgm,
+}
+
+impl Format {
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            Format::Pbm => "pbm",
+            Format::Pgm => "pgm",
+        }
+    }
+}
+
+// Lit pixels as reverse-video blocks, two columns wide so the letters keep
+// their shape in a terminal.
+pub fn ansi(display: &Display) -> String {
+    let mut rows = vec![];
+    for y in 0..display.height() {
+        let mut row = String::new();
+        for x in 0..display.width() {
+            if display.get(x, y) {
+                row.push_str("\x1b[7m  \x1b[0m");
+            } else {
+                row.push_str("  ");
+            }
+        }
+        rows.push(row);
+    }
+    rows.join("\n")
+}
+
+// Plain PBM, where 1 is a black pixel, so lit pixels come out dark on a
+// white page.
+pub fn pbm(display: &Display) -> String {
+    let mut image = format!("P1\n{} {}\n", display.width(), display.height());
+    for y in 0..display.height() {
+        let row = (0..display.width())
+            .map(|x| if display.get(x, y) { "1" } else { "0" })
+            .collect::<Vec<_>>();
+        image.push_str(&row.join(" "));
+        image.push('\n');
+    }
+    image
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorder() -> Recorder {
+        Recorder::record(Display::with_size(4, 2), "rect 2x1\nrotate row y=0 by 1").unwrap()
+    }
+
+    #[test]
+    fn it_records_a_frame_per_instruction() {
+        let recorder = recorder();
+        let frames = recorder.frames();
+        assert_eq!(3, frames.len());
+        assert_eq!(None, frames[0].instruction);
+        assert_eq!(0, frames[0].display.on_pixels());
+        assert_eq!(Some("rect 2x1".to_string()), frames[1].instruction);
+        assert_eq!("##..\n....", frames[1].display.print());
+        assert_eq!(".##.\n....", recorder.display().print());
+    }
+
+    #[test]
+    fn it_reports_bad_instructions_by_line() {
+        let error = Recorder::record(Display::with_size(4, 2), "rect 2x1\nrect 5x1").err().unwrap();
+        assert_eq!(2, error.line);
+        assert_eq!(6, error.column);
+    }
+
+    #[test]
+    fn it_exports_pbm() {
+        assert_eq!("P1\n4 2\n0 1 1 0\n0 0 0 0\n", pbm(recorder().display()));
+    }
+
+    #[test]
+    fn it_exports_pgm_with_changes_highlighted() {
+        assert_eq!("P2\n4 2\n255\n170 0 85 255\n255 255 255 255\n", recorder().pgm(2));
+    }
+
+    #[test]
+    fn it_renders_ansi_frames() {
+        let frames = recorder().ansi_frames();
+        assert_eq!(3, frames.len());
+        assert!(frames[0].starts_with("\x1b[H\x1b[2J0/2 start\n"));
+        assert!(frames[2].contains("2/2 rotate row y=0 by 1"));
+        assert_eq!("  \x1b[7m  \x1b[0m\x1b[7m  \x1b[0m  \n        ", ansi(recorder().display()));
+    }
+}
@@ -0,0 +1,191 @@
+use std::iter;
+use parse::ParseError;
+use solution::{Answer, Solution};
+
+pub fn strip_whitespace(input: String) -> String {
+    input.split_whitespace().collect()
+}
+
+pub fn decompress(input: String) -> String {
+    let tmp = markers(input.split_whitespace().collect());
+    let mut decompressed = tmp.0;
+    let mut remaining = tmp.1;
+    while !remaining.is_empty() {
+        let (x, y) = markers(remaining);
+        decompressed = decompressed + &x;
+        remaining = y;
+    }
+    decompressed
+}
+
+pub fn markers(input: String) -> (String, String) {
+    let mut parts = input.splitn(3, ['(', ')']);
+
+    let leading = parts.next().unwrap().to_string();
+
+    let tmp = parts.next();
+    if tmp.is_none() {
+        return (leading, "".to_string());
+    }
+
+    let mut middle = tmp.unwrap().split('x');
+    let to_repeat: usize = middle.next().unwrap().parse().unwrap();
+    let count: usize = middle.next().unwrap().parse().unwrap();
+
+    let trailing = parts.next().unwrap();
+
+    let repeats: String = iter::repeat_n(&trailing[..to_repeat], count).collect();
+
+    let end = trailing[to_repeat..].to_string();
+
+    (leading + &repeats, end)
+}
+
+// Checks each marker is whole and repeats no more than the data after it,
+// inside repeated data too as part 2 expands that as well. Only the markers'
+// own brackets may appear, since `markers` splits the data on them.
+pub fn validate(input: &str) -> Result<(), ParseError> {
+    let mut data = vec![];
+    let mut positions = vec![];
+    for (index, line) in input.lines().enumerate() {
+        for (column, c) in line.char_indices().filter(|&(_, c)| !c.is_whitespace()) {
+            for byte in c.to_string().bytes() {
+                data.push(byte);
+                positions.push((index + 1, column + 1));
+            }
+        }
+    }
+    check_markers(&data, 0, data.len()).map_err(|(at, expected)| {
+        let (line, column) = positions[at];
+        let token = input.lines().nth(line - 1).unwrap()[column - 1..].chars().next().unwrap();
+        ParseError::new(column, &token.to_string(), expected).on_line(line)
+    })
+}
+
+fn check_markers(data: &[u8], start: usize, end: usize) -> Result<(), (usize, &'static str)> {
+    let mut i = start;
+    while i < end {
+        match data[i] {
+            b'(' => {
+                let close = data[i..end].iter().position(|&b| b == b')').map(|offset| i + offset)
+                    .ok_or((i, "a marker like `(2x3)`"))?;
+                let marker = String::from_utf8_lossy(&data[i + 1..close]).into_owned();
+                let mut fields = marker.splitn(2, 'x').map(|field| field.parse::<usize>());
+                let length = match (fields.next(), fields.next()) {
+                    (Some(Ok(length)), Some(Ok(_))) => length,
+                    _ => return Err((i, "a marker like `(2x3)`")),
+                };
+                if length > end - close - 1 {
+                    return Err((i, "a marker repeating no more than the data after it"));
+                }
+                check_markers(data, close + 1, close + 1 + length)?;
+                i = close + 1 + length;
+            },
+            b')' => return Err((i, "a letter or a marker")),
+            byte if byte.is_ascii() => i += 1,
+            _ => return Err((i, "a letter or a marker")),
+        }
+    }
+    Ok(())
+}
+
+pub fn decompressed_length(input: &str) -> usize {
+    let mut parts = input.splitn(3, ['(', ')']);
+
+    let leading = parts.next().unwrap().len();
+
+    let tmp = parts.next();
+    if tmp.is_none() {
+        return leading;
+    }
+
+    let mut middle = tmp.unwrap().split('x');
+    let to_repeat: usize = middle.next().unwrap().parse().unwrap();
+    let count: usize = middle.next().unwrap().parse().unwrap();
+
+    let trailing = parts.next().unwrap();
+
+    leading + (count * decompressed_length(&trailing[..to_repeat])) + decompressed_length(&trailing[to_repeat..])
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        validate(input)?;
+        Ok(strip_whitespace(input.to_string()))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        Ok(decompress(input.clone()).len().to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        Ok(decompressed_length(input).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_decompresses_no_markers() {
+        assert_eq!("ADVENT", decompress("ADVENT".to_string()));
+    }
+
+    #[test]
+    fn it_extracts_string_to_repeat() {
+        let compress = markers("A(2x5)BC".to_string());
+        assert_eq!("ABCBCBCBCBC", compress.0);
+    }
+
+    #[test]
+    fn it_decompresses_simple_marker() {
+        assert_eq!("ABBBBBC", decompress("A(1x5)BC".to_string()));
+    }
+
+    #[test]
+    fn it_decompresses_more() {
+        assert_eq!("X(3x3)ABC(3x3)ABCY", decompress("X(8x2)(3x3)ABCY".to_string()));
+    }
+
+    #[test]
+    fn it_decompresses_foo() {
+        assert_eq!("ABCBCDEFEFG", decompress("A(2x2)BCD(2x2)EFG".to_string()));
+    }
+
+    #[test]
+    fn it_counts_with_no_markers() {
+        assert_eq!(6, decompressed_length("ADVENT"));
+    }
+
+    #[test]
+    fn it_counts_with_one_marker() {
+        assert_eq!(7, decompressed_length("A(1x5)BC"));
+    }
+
+    #[test]
+    fn it_counts_with_repeated_markers() {
+        assert_eq!(241920, decompressed_length("(27x12)(20x12)(13x14)(7x10)(1x12)A"));
+    }
+
+    #[test]
+    fn it_still_counts_with_nested() {
+        assert_eq!(445, decompressed_length("(25x3)(3x3)ABC(2x3)XY(5x2)PQRSTX(18x9)(3x2)TWO(5x7)SEVEN"));
+    }
+
+    #[test]
+    fn it_reports_malformed_markers() {
+        assert!(Day9::parse("A(1x5)BC\n(3x3)XYZ").is_ok());
+        let error = Day9::parse("A(1x5)BC\n  X(3y3)ABC").unwrap_err();
+        assert_eq!("line 2, column 4: expected a marker like `(2x3)`, found `(`", error.to_string());
+        assert_eq!(ParseError::new(2, "(", "a marker like `(2x3)`"), Day9::parse("A(1x").unwrap_err());
+        assert_eq!(ParseError::new(1, "(", "a marker repeating no more than the data after it"), Day9::parse("(5x2)AB").unwrap_err());
+        assert_eq!(ParseError::new(7, "(", "a marker repeating no more than the data after it"), Day9::parse("(8x2)A(3x3)AB").unwrap_err());
+        assert_eq!(ParseError::new(2, ")", "a letter or a marker"), Day9::parse("A)B").unwrap_err());
+        assert_eq!(ParseError::new(2, "é", "a letter or a marker"), Day9::parse("Aé").unwrap_err());
+    }
+}
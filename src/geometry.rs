@@ -0,0 +1,188 @@
+use std::ops::{Add, Mul, Sub};
+
+// y grows to the north, so "up" on a screen whose rows count downwards is
+// `Direction::North` with the row negated.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+impl Point {
+    pub fn new(x: i32, y: i32) -> Point {
+        Point { x, y }
+    }
+
+    pub fn step(&self, direction: Direction) -> Point {
+        *self + direction.delta()
+    }
+
+    pub fn moved(&self, direction: Direction, distance: i32) -> Point {
+        *self + direction.delta() * distance
+    }
+
+    pub fn neighbours(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        DIRECTIONS.iter().map(move |&direction| point.step(direction))
+    }
+
+    // Includes the diagonals, clockwise from north.
+    pub fn neighbours8(&self) -> impl Iterator<Item = Point> {
+        let point = *self;
+        [(0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1), (-1, 0), (-1, 1)].iter()
+            .map(move |&(x, y)| point + Point::new(x, y))
+    }
+
+    pub fn manhattan(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Point) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((x, y): (i32, i32)) -> Point {
+        Point { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i32) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+pub const DIRECTIONS: [Direction; 4] = [Direction::North, Direction::East, Direction::South, Direction::West];
+
+impl Direction {
+    pub fn turn_right(&self) -> Direction {
+        match *self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        match *self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    pub fn reverse(&self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn delta(&self) -> Point {
+        match *self {
+            Direction::North => Point::new(0, 1),
+            Direction::East => Point::new(1, 0),
+            Direction::South => Point::new(0, -1),
+            Direction::West => Point::new(-1, 0),
+        }
+    }
+
+    // U, D, L and R, as used by the keypad and the vault doors.
+    pub fn from_letter(letter: char) -> Option<Direction> {
+        match letter {
+            'U' => Some(Direction::North),
+            'D' => Some(Direction::South),
+            'L' => Some(Direction::West),
+            'R' => Some(Direction::East),
+            _ => None,
+        }
+    }
+
+    pub fn letter(&self) -> char {
+        match *self {
+            Direction::North => 'U',
+            Direction::South => 'D',
+            Direction::West => 'L',
+            Direction::East => 'R',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reverses_directions() {
+        for direction in DIRECTIONS.iter() {
+            assert_eq!(ORIGIN, direction.delta() + direction.reverse().delta());
+            assert_eq!(*direction, direction.reverse().reverse());
+        }
+    }
+
+    #[test]
+    fn it_reads_letters() {
+        for direction in DIRECTIONS.iter() {
+            assert_eq!(Some(*direction), Direction::from_letter(direction.letter()));
+        }
+        assert_eq!(None, Direction::from_letter('N'));
+    }
+
+    #[test]
+    fn it_moves_points() {
+        let point = Point::new(2, -1);
+        assert_eq!(Point::new(2, 0), point.step(Direction::North));
+        assert_eq!(Point::new(-3, -1), point.moved(Direction::West, 5));
+        assert_eq!(Point::new(4, -2), point * 2);
+        assert_eq!(Point::new(1, -1), point - Point::new(1, 0));
+    }
+
+    #[test]
+    fn it_lists_neighbours() {
+        let point = Point::new(1, 1);
+        let neighbours = point.neighbours().collect::<Vec<_>>();
+        assert_eq!(vec![Point::new(1, 2), Point::new(2, 1), Point::new(1, 0), Point::new(0, 1)], neighbours);
+        let neighbours8 = point.neighbours8().collect::<Vec<_>>();
+        assert_eq!(8, neighbours8.len());
+        assert!(neighbours8.iter().all(|n| n.chebyshev(&point) == 1));
+        assert_eq!(4, neighbours8.iter().filter(|n| n.manhattan(&point) == 2).count());
+    }
+
+    #[test]
+    fn it_measures_distances() {
+        let a = Point::new(-2, 3);
+        let b = Point::new(4, 1);
+        assert_eq!(8, a.manhattan(&b));
+        assert_eq!(6, a.chebyshev(&b));
+        assert_eq!(5, a.manhattan(&ORIGIN));
+    }
+}
@@ -1,3 +1,128 @@
+extern crate advent2016;
+
+use advent2016::runner;
+use advent2016::runner::RunError;
+use std::env;
+use std::fs::File;
+use std::io::Read;
+use std::process;
+
+const USAGE: &str = "usage: advent2016 run <day> [<part>] [--input <file>]
+       advent2016 run all [--inputs <dir>]";
+
+// Only mistakes in the arguments get the usage printed after them.
+enum Failure {
+    Usage(String),
+    Run(String),
+}
+
 fn main() {
-    println!("Hello, world!");
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => (),
+        Err(Failure::Usage(message)) => {
+            eprintln!("error: {}", message);
+            eprintln!("{}", USAGE);
+            process::exit(1);
+        },
+        Err(Failure::Run(message)) => {
+            eprintln!("error: {}", message);
+            process::exit(1);
+        },
+    }
+}
+
+fn run(args: &[String]) -> Result<(), Failure> {
+    match args.first().map(|x| x.as_str()) {
+        Some("run") => (),
+        Some(command) => return Err(Failure::Usage(format!("unknown command {}", command))),
+        None => return Err(Failure::Usage("missing command".to_string())),
+    }
+
+    let (positional, input) = split_flags(&args[1..]).map_err(Failure::Usage)?;
+    match positional.first().map(|x| x.as_str()) {
+        Some("all") => {
+            if positional.len() > 1 {
+                return Err(Failure::Usage("`run all` takes no day or part".to_string()));
+            }
+            run_all(&input.unwrap_or_else(|| "inputs".to_string()));
+            Ok(())
+        },
+        Some(raw_day) => {
+            let day = parse_number("day", raw_day).map_err(Failure::Usage)?;
+            let parts = match positional.get(1) {
+                Some(raw_part) => vec![parse_number("part", raw_part).map_err(Failure::Usage)?],
+                None => vec![1, 2],
+            };
+            if positional.len() > 2 {
+                return Err(Failure::Usage(format!("unexpected argument {}", positional[2])));
+            }
+            for part in parts.iter() {
+                runner::check(day, *part).map_err(|e| Failure::Usage(e.to_string()))?;
+            }
+            let path = input.unwrap_or_else(|| default_input(day));
+            let contents = read_input(&path).map_err(Failure::Run)?;
+            for part in parts {
+                let answer = runner::solve(day, part, &contents).map_err(|e| Failure::Run(match e {
+                    RunError::Parse(_) => format!("{}: {}", path, e),
+                    _ => e.to_string(),
+                }))?;
+                println!("{}", answer);
+            }
+            Ok(())
+        },
+        None => Err(Failure::Usage("missing day".to_string())),
+    }
+}
+
+fn run_all(dir: &str) {
+    for day in 1..(runner::DAYS + 1) {
+        let path = format!("{}/day{}", dir, day);
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(message) => {
+                println!("day {}: skipped, {}", day, message);
+                continue;
+            },
+        };
+        for part in 1..3 {
+            match runner::solve(day, part, &input) {
+                Ok(answer) => println!("day {} part {}: {}", day, part, answer),
+                Err(error) => println!("day {} part {}: {}", day, part, error),
+            }
+        }
+    }
+}
+
+fn split_flags(args: &[String]) -> Result<(Vec<String>, Option<String>), String> {
+    let mut positional = vec![];
+    let mut input = None;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        if arg == "--input" || arg == "--inputs" {
+            match iter.next() {
+                Some(value) => input = Some(value.clone()),
+                None => return Err(format!("{} needs a value", arg)),
+            }
+        } else {
+            positional.push(arg.clone());
+        }
+    }
+    Ok((positional, input))
+}
+
+fn parse_number(name: &str, raw: &str) -> Result<usize, String> {
+    raw.parse().map_err(|_| format!("{} must be a number, got {}", name, raw))
+}
+
+fn default_input(day: usize) -> String {
+    format!("inputs/day{}", day)
+}
+
+fn read_input(path: &str) -> Result<String, String> {
+    let mut input = String::new();
+    File::open(path)
+        .and_then(|mut file| file.read_to_string(&mut input))
+        .map_err(|e| format!("couldn't read {}: {}", path, e))?;
+    Ok(input)
 }
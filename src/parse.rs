@@ -0,0 +1,197 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, token: &str, expected: &str) -> ParseError {
+        ParseError {
+            line: 1,
+            column,
+            token: token.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    pub fn on_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+
+    pub fn shifted(self, columns: usize) -> ParseError {
+        ParseError { column: self.column + columns, ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, ", self.line, self.column, self.expected)?;
+        if self.token.is_empty() {
+            write!(f, "found end of line")
+        } else {
+            write!(f, "found `{}`", self.token)
+        }
+    }
+}
+
+// For parsers that read their input as they go.
+#[derive(Debug)]
+pub enum ReadError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReadError::Io(ref error) => write!(f, "{}", error),
+            ReadError::Parse(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(error: io::Error) -> ReadError {
+        ReadError::Io(error)
+    }
+}
+
+impl From<ParseError> for ReadError {
+    fn from(error: ParseError) -> ReadError {
+        ReadError::Parse(error)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    pub column: usize,
+}
+
+impl<'a> Token<'a> {
+    pub fn parse<T: FromStr>(&self, expected: &str) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    pub fn expect(&self, text: &str) -> Result<(), ParseError> {
+        if self.text == text {
+            Ok(())
+        } else {
+            Err(self.error(&format!("`{}`", text)))
+        }
+    }
+
+    pub fn error(&self, expected: &str) -> ParseError {
+        ParseError::new(self.column, self.text, expected)
+    }
+}
+
+pub struct Tokens<'a> {
+    source: &'a str,
+    offset: usize,
+}
+
+impl<'a> Tokens<'a> {
+    pub fn new(source: &'a str) -> Tokens<'a> {
+        Tokens {
+            source,
+            offset: 0,
+        }
+    }
+
+    pub fn next_token(&mut self, expected: &str) -> Result<Token<'a>, ParseError> {
+        let rest = &self.source[self.offset..];
+        let start = self.offset + (rest.len() - rest.trim_start().len());
+        let rest = &self.source[start..];
+        let len = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        self.offset = start + len;
+        let token = Token {
+            text: &self.source[start..start + len],
+            column: start + 1,
+        };
+        if token.text.is_empty() {
+            Err(token.error(expected))
+        } else {
+            Ok(token)
+        }
+    }
+
+    pub fn skip(&mut self, count: usize) -> Result<(), ParseError> {
+        for _ in 0..count {
+            self.next_token("more words")?;
+        }
+        Ok(())
+    }
+
+    pub fn finish(&mut self) -> Result<(), ParseError> {
+        match self.next_token("") {
+            Ok(token) => Err(token.error("end of line")),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+pub fn parse_lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    let mut parsed = vec![];
+    for (index, line) in input.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+        let indent = line.len() - trimmed.len();
+        let item = trimmed.trim_end().parse()
+            .map_err(|e: ParseError| e.shifted(indent).on_line(index + 1))?;
+        parsed.push(item);
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Number(u32);
+
+    impl FromStr for Number {
+        type Err = ParseError;
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            Tokens::new(s).next_token("a number")?.parse("a number").map(Number)
+        }
+    }
+
+    #[test]
+    fn it_finds_token_columns() {
+        let mut tokens = Tokens::new("cpy  41 a");
+        assert_eq!(Token { text: "cpy", column: 1 }, tokens.next_token("").unwrap());
+        assert_eq!(Token { text: "41", column: 6 }, tokens.next_token("").unwrap());
+        assert_eq!(Token { text: "a", column: 9 }, tokens.next_token("").unwrap());
+        assert!(tokens.finish().is_ok());
+    }
+
+    #[test]
+    fn it_reports_missing_tokens() {
+        let mut tokens = Tokens::new("inc ");
+        tokens.skip(1).unwrap();
+        assert_eq!(ParseError::new(5, "", "a register"), tokens.next_token("a register").unwrap_err());
+    }
+
+    #[test]
+    fn it_skips_blank_lines() {
+        assert_eq!(vec![Number(1), Number(2)], parse_lines::<Number>("1\n\n  2\n").unwrap());
+    }
+
+    #[test]
+    fn it_reports_lines() {
+        let error = parse_lines::<Number>("1\n  x").unwrap_err();
+        assert_eq!(2, error.line);
+        assert_eq!(3, error.column);
+        assert_eq!("line 2, column 3: expected a number, found `x`", error.to_string());
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+
+use day1;
+use day2;
+use day3;
+use day4;
+use day5;
+use day6;
+use day7;
+use day8;
+use day9;
+use day10;
+use day11;
+use day12;
+use day13;
+use day14;
+use day15;
+use day16;
+use day17;
+use day18;
+use day19;
+use day20;
+use parse::ParseError;
+use solution;
+use solution::SolveError;
+
+pub const DAYS: usize = 20;
+
+#[derive(Debug, PartialEq)]
+pub enum RunError {
+    UnknownDay(usize),
+    UnknownPart(usize),
+    Unsolved(usize),
+    NoAnswer,
+    Parse(ParseError),
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RunError::UnknownDay(day) => write!(f, "unknown day {}, expected 1 to {}", day, DAYS),
+            RunError::UnknownPart(part) => write!(f, "unknown part {}, expected 1 or 2", part),
+            RunError::Unsolved(day) => write!(f, "day {} has not been solved yet", day),
+            RunError::NoAnswer => write!(f, "no answer found for this input"),
+            RunError::Parse(ref error) => write!(f, "bad input, {}", error),
+        }
+    }
+}
+
+pub fn check(day: usize, part: usize) -> Result<(), RunError> {
+    if day == 0 || day > DAYS {
+        return Err(RunError::UnknownDay(day));
+    }
+    if part == 0 || part > 2 {
+        return Err(RunError::UnknownPart(part));
+    }
+    Ok(())
+}
+
+pub fn solve(day: usize, part: usize, input: &str) -> Result<String, RunError> {
+    check(day, part)?;
+    let answer = match day {
+        1 => solution::solve::<day1::Day1>(part, input),
+        2 => solution::solve::<day2::Day2>(part, input),
+        3 => solution::solve::<day3::Day3>(part, input),
+        4 => solution::solve::<day4::Day4>(part, input),
+        5 => solution::solve::<day5::Day5>(part, input),
+        6 => solution::solve::<day6::Day6>(part, input),
+        7 => solution::solve::<day7::Day7>(part, input),
+        8 => solution::solve::<day8::Day8>(part, input),
+        9 => solution::solve::<day9::Day9>(part, input),
+        10 => solution::solve::<day10::Day10>(part, input),
+        11 => solution::solve::<day11::Day11>(part, input),
+        12 => solution::solve::<day12::Day12>(part, input),
+        13 => solution::solve::<day13::Day13>(part, input),
+        14 => solution::solve::<day14::Day14>(part, input),
+        15 => solution::solve::<day15::Day15>(part, input),
+        16 => solution::solve::<day16::Day16>(part, input),
+        17 => solution::solve::<day17::Day17>(part, input),
+        18 => solution::solve::<day18::Day18>(part, input),
+        19 => solution::solve::<day19::Day19>(part, input),
+        20 => solution::solve::<day20::Day20>(part, input),
+        _ => unreachable!(),
+    };
+    answer.map_err(|error| match error {
+        SolveError::NoAnswer => RunError::NoAnswer,
+        SolveError::Unsolved => RunError::Unsolved(day),
+        SolveError::Parse(error) => RunError::Parse(error),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_unknown_days() {
+        assert_eq!(Err(RunError::UnknownDay(0)), solve(0, 1, ""));
+        assert_eq!(Err(RunError::UnknownDay(26)), solve(26, 1, ""));
+    }
+
+    #[test]
+    fn it_rejects_unknown_parts() {
+        assert_eq!(Err(RunError::UnknownPart(3)), solve(1, 3, ""));
+    }
+
+    #[test]
+    fn it_reports_unsolved_days() {
+        assert_eq!(Err(RunError::Unsolved(11)), solve(11, 1, ""));
+    }
+
+    #[test]
+    fn it_solves_a_day() {
+        assert_eq!(Ok("1985".to_string()), solve(2, 1, "ULL\nRRDDD\nLURDL\nUUUUD"));
+        assert_eq!(Ok("5DB3".to_string()), solve(2, 2, "ULL\nRRDDD\nLURDL\nUUUUD"));
+    }
+
+    #[test]
+    fn it_reports_bad_input() {
+        let error = ParseError::new(5, "q", "a register").on_line(2);
+        assert_eq!(Err(RunError::Parse(error)), solve(12, 1, "inc a\ninc q"));
+    }
+
+    #[test]
+    fn it_reports_missing_answers() {
+        assert_eq!(Err(RunError::NoAnswer), solve(1, 2, "R5, L5, R5, R3"));
+    }
+}
@@ -0,0 +1,83 @@
+use std::fmt;
+use std::io;
+use std::io::Read;
+use std::process;
+
+use parse::{ParseError, ReadError};
+
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+pub type Answer = Result<String, SolveError>;
+
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    NoAnswer,
+    Unsolved,
+    Parse(ParseError),
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SolveError::NoAnswer => write!(f, "no answer found for this input"),
+            SolveError::Unsolved => write!(f, "this puzzle has not been solved yet"),
+            SolveError::Parse(ref error) => write!(f, "bad input, {}", error),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> SolveError {
+        SolveError::Parse(error)
+    }
+}
+
+pub fn solve<S: Solution>(part: usize, input: &str) -> Answer {
+    let parsed = S::parse(input)?;
+    if part == 1 {
+        S::part1(&parsed)
+    } else {
+        S::part2(&parsed)
+    }
+}
+
+pub fn run_stdin<S: Solution>(part: usize) {
+    let mut input = String::new();
+    if let Err(error) = io::stdin().read_to_string(&mut input) {
+        fail(ReadError::Io(error));
+    }
+    match solve::<S>(part, &input) {
+        Ok(answer) => println!("{}", answer),
+        Err(error) => fail(error),
+    }
+}
+
+fn fail<E: fmt::Display>(error: E) -> ! {
+    eprintln!("error: {}", error);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use day2::Day2;
+    use day11::Day11;
+
+    #[test]
+    fn it_solves_each_part() {
+        let input = "ULL\nRRDDD\nLURDL\nUUUUD";
+        assert_eq!(Ok("1985".to_string()), solve::<Day2>(1, input));
+        assert_eq!(Ok("5DB3".to_string()), solve::<Day2>(2, input));
+    }
+
+    #[test]
+    fn it_reports_unsolved_parts() {
+        assert_eq!(Err(SolveError::Unsolved), solve::<Day11>(1, ""));
+    }
+}
@@ -1,10 +1,22 @@
-use std::io;
-use std::io::Read;
+use parse::ParseError;
+use solution::{Answer, Solution, SolveError};
 
-fn main() {
-    let mut input = String::new();
-    let _ = io::stdin().read_to_string(&mut input);
-    println!("TODO");
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &Self::Input) -> Answer {
+        Err(SolveError::Unsolved)
+    }
+
+    fn part2(_input: &Self::Input) -> Answer {
+        Err(SolveError::Unsolved)
+    }
 }
 
 #[cfg(test)]